@@ -1,15 +1,18 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use footballscore::{
-    football_fixtures_data::FootballFixturesData, football_teams_data::FootballTeamsData,
+    football_fixtures_data::{FootballFixturesData, Timezone},
+    football_teams_data::FootballTeamsData,
 };
 
+#[allow(clippy::disallowed_methods)]
 pub fn criterion_benchmark(c: &mut Criterion) {
     let buf = include_str!("../tests/resource/fixtures.json");
     let data: FootballFixturesData = serde_json::from_str(buf).unwrap();
+    let timezone = Timezone::default();
 
     c.bench_function("footballscore_fixtures_data", |b| {
-        b.iter(|| data.get_current_fixtures())
+        b.iter(|| data.get_current_fixtures(&timezone))
     });
 
     let buf = include_str!("../tests/resource/teams.json");
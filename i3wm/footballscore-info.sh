@@ -13,8 +13,9 @@ fi
 if ! ping=$(ping -n -c 1 -W 1 $HOST); then
     echo "%{F#aa5a5b}%{T3}"
 else
-    # Run the footballscore command with the provided argument
-    cli=$(footballscore -k=1e5765fc0c22df4e4ccf20581c2ef3d7 -c=529 --next-match=1)
+    # Run the footballscore command with the provided argument, the colour
+    # of the match follows its status (live, upcoming, finished)
+    cli=$(footballscore -k=1e5765fc0c22df4e4ccf20581c2ef3d7 -c=529 --next-match=1 --format=polybar)
 
     ball_icon="%{F#8abeb7}%{F-}"
     cli_with_clock="$ball_icon $cli"
@@ -1,14 +1,13 @@
 #!/bin/bash
 
-TMP=${XDG_RUNTIME_DIR:-/tmp}/"$UID"_footballscore_notification
-touch "$TMP"
-
-DIFF=${DIFF:-0}
-
+# Only what changed since the previous click, the last seen state of the
+# match is kept in $XDG_STATE_HOME/footballscore
 send_notification() {
-	BODY=$(footballscore "-k=1e5765fc0c22df4e4ccf20581c2ef3d7" "-c=529" "--next-match=1" | head -n7)
-	dunstify -h string:x-canonical-private-synchronous:footballscore \
-		"$BODY" -u NORMAL
+	BODY=$(footballscore "-k=1e5765fc0c22df4e4ccf20581c2ef3d7" "-c=529" "--since-last-run")
+	if [ -n "$BODY" ]; then
+		dunstify -h string:x-canonical-private-synchronous:footballscore \
+			"$BODY" -u NORMAL
+	fi
 }
 
-send_notification "$DIFF"
+send_notification
@@ -5,11 +5,11 @@ use std::{
     env::{remove_var, set_var, var_os},
     ffi::{OsStr, OsString},
     ops::Deref,
-    path::Path,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 
-use crate::{ApiStringType, Error, StringType};
+use crate::{football_api::api_base_url, ApiStringType, Error, StringType};
 
 /// Configuration data
 #[derive(Default, Debug, Deserialize, PartialEq, Eq)]
@@ -17,13 +17,41 @@ pub struct ConfigInner {
     /// api-football.com api key
     pub api_key: Option<ApiStringType>,
 
-    /// api-football.com api endpoint
+    /// api-football.com api endpoint, a host (reached over https) or a full
+    /// base url, e.g. `http://127.0.0.1:8000/v3` for a local stand-in
     #[serde(default = "default_api_endpoint")]
     pub api_endpoint: StringType,
 
     /// Optional (default is `529 - Barcelona`)
     #[serde(default = "default_club_id")]
     pub club_id: u16,
+
+    /// IANA name of the zone kick-off times are shown in, e.g. `Europe/Madrid`
+    /// (optional, default is the system local zone)
+    pub timezone: Option<StringType>,
+
+    /// Format string matches are written out with, see `FixtureTemplate`
+    /// (optional)
+    pub template: Option<StringType>,
+
+    /// Directory the last seen fixtures are kept in, see `FixtureStore`
+    /// (optional, default is `$XDG_STATE_HOME/footballscore`)
+    pub state_dir: Option<PathBuf>,
+
+    /// Directory the api responses are cached in, see `ApiCache`
+    /// (optional, default is `$XDG_CACHE_HOME/footballscore`)
+    pub cache_dir: Option<PathBuf>,
+
+    /// Requests a day kept in reserve, api calls are refused once no more
+    /// are left (optional, default is `0`)
+    #[serde(default)]
+    pub quota_floor: u32,
+
+    /// Comma separated urls the fixture changes are posted to (optional)
+    pub webhook_urls: Option<StringType>,
+
+    /// Secret the webhook payloads are signed with (optional)
+    pub webhook_secret: Option<StringType>,
 }
 
 fn default_api_endpoint() -> StringType {
@@ -65,7 +93,7 @@ impl Config {
     /// use anyhow::Error;
     ///
     /// # fn main() -> Result<(), Error> {
-    /// # let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+    /// # let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID", "TIMEZONE", "TEMPLATE", "STATE_DIR"]);
     /// # set_var("API_KEY", "api_key_value");
     /// # set_var("API_ENDPOINT", "v3.football.api-sports.io");
     /// let config = Config::init_config(None)?;
@@ -77,7 +105,8 @@ impl Config {
     /// ```
     /// # Errors
     ///
-    /// Will return Error if unable to deserialize env variables
+    /// Will return Error if unable to deserialize env variables, or if
+    /// `API_ENDPOINT` is not a valid base url
     pub fn init_config(config_path: Option<&Path>) -> Result<Self, Error> {
         let fname = config_path.unwrap_or_else(|| Path::new("config.env"));
         let config_dir = dirs::config_dir().unwrap_or_else(|| "./".into());
@@ -96,6 +125,7 @@ impl Config {
         }
 
         let conf: ConfigInner = envy::from_env()?;
+        api_base_url(&conf.api_endpoint)?;
 
         Ok(Self(Arc::new(conf)))
     }
@@ -165,11 +195,25 @@ mod tests {
     fn test_config() -> Result<(), Error> {
         assert_eq!(Config::new(), Config::default());
 
-        let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+        let _env = TestEnvs::new(&[
+            "API_KEY",
+            "API_ENDPOINT",
+            "CLUB_ID",
+            "TIMEZONE",
+            "TEMPLATE",
+            "STATE_DIR",
+            "CACHE_DIR",
+            "QUOTA_FLOOR",
+        ]);
 
         set_var("API_KEY", "1e5765fc0c22df4e4ccf20581c2ef3d7");
         set_var("API_ENDPOINT", "test.local");
         set_var("CLUB_ID", "529");
+        remove_var("TIMEZONE");
+        remove_var("TEMPLATE");
+        set_var("STATE_DIR", "/tmp/footballscore");
+        set_var("CACHE_DIR", "/tmp/footballscore-cache");
+        set_var("QUOTA_FLOOR", "10");
 
         let conf = Config::init_config(None)?;
         drop(_env);
@@ -186,17 +230,41 @@ mod tests {
 
         assert_eq!(&conf.api_endpoint, "test.local");
         assert_eq!(conf.club_id, 529);
+        assert_eq!(conf.timezone, None);
+        assert_eq!(conf.state_dir, Some("/tmp/footballscore".into()));
+        assert_eq!(conf.cache_dir, Some("/tmp/footballscore-cache".into()));
+        assert_eq!(conf.quota_floor, 10);
+
+        // a local stand-in of the api, and an endpoint requests cannot go to
+        let _env = TestEnvs::new(&["API_ENDPOINT"]);
+        set_var("API_ENDPOINT", "http://127.0.0.1:8000/v3");
+        let conf = Config::init_config(None)?;
+        assert_eq!(&conf.api_endpoint, "http://127.0.0.1:8000/v3");
+
+        set_var("API_ENDPOINT", "ftp://127.0.0.1/v3");
+        assert!(Config::init_config(None).is_err());
+        drop(_env);
 
         Ok(())
     }
 
     #[test]
     fn test_config_file() -> Result<(), Error> {
-        let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+        let _env = TestEnvs::new(&[
+            "API_KEY",
+            "API_ENDPOINT",
+            "CLUB_ID",
+            "TIMEZONE",
+            "TEMPLATE",
+            "STATE_DIR",
+        ]);
 
         remove_var("API_KEY");
         remove_var("API_ENDPOINT");
         remove_var("CLUB_ID");
+        remove_var("TIMEZONE");
+        remove_var("TEMPLATE");
+        remove_var("STATE_DIR");
 
         let config_data = include_bytes!("../tests/config/config.env");
         let config_file = NamedTempFile::new()?;
@@ -216,6 +284,11 @@ mod tests {
 
         assert_eq!(&conf.api_endpoint, "test.local");
         assert_eq!(conf.club_id, 529);
+        assert_eq!(conf.timezone, Some("Europe/Madrid".into()));
+        assert_eq!(
+            conf.template,
+            Some("{kickoff:%H:%M} {home} vs {away}".into())
+        );
 
         Ok(())
     }
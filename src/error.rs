@@ -14,6 +14,9 @@ use reqwest::Error as ReqwestError;
 #[cfg(feature = "cli")]
 use reqwest::header::InvalidHeaderValue;
 
+#[cfg(feature = "notify")]
+use zbus::Error as ZbusError;
+
 use crate::StringType;
 
 #[derive(ThisError, Debug)]
@@ -39,6 +42,9 @@ pub enum Error {
     #[error("Invalid Input Error {0}")]
     InvalidInputError(StringType),
 
+    #[error("Quota Error {0}")]
+    QuotaError(StringType),
+
     #[cfg(feature = "cli")]
     #[error("Clap CLI Parser Error {0}")]
     ClapError(#[from] ClapError),
@@ -54,4 +60,8 @@ pub enum Error {
     #[cfg(feature = "cli")]
     #[error("Task Join Error {0}")]
     JoinError(#[from] JoinError),
+
+    #[cfg(feature = "notify")]
+    #[error("D-Bus Error {0}")]
+    ZbusError(#[from] ZbusError),
 }
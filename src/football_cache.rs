@@ -0,0 +1,310 @@
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    config::Config, football_ratelimit::RateLimit, format_string, ApiStringType, Error, StringType,
+};
+
+/// Name of the file the latest rate limit is kept in
+const RATE_LIMIT_FILE: &str = "ratelimit.json";
+
+/// Clubs barely ever change
+const TEAMS_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Standings change once per match day
+const STANDINGS_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Past meetings of two clubs only change when they meet again
+const HEAD_TO_HEAD_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Next and last fixtures change when one kicks off or ends
+const SCHEDULE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The api updates live fixtures every 15 seconds
+const LIVE_TTL: Duration = Duration::from_secs(15);
+
+/// Fixtures of a day, events, lineups and statistics change during a match
+const MATCH_DAY_TTL: Duration = Duration::from_secs(60);
+
+/// Api responses kept on disk, one JSON file per command and query, so
+/// repeated runs do not use up the daily request quota
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiCache {
+    dir: PathBuf,
+    refresh: bool,
+}
+
+impl ApiCache {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            refresh: false,
+        }
+    }
+
+    /// Cache in `CACHE_DIR` if set, otherwise in the default directory
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.cache_dir.clone().unwrap_or_else(Self::default_dir))
+    }
+
+    /// `$XDG_CACHE_HOME/footballscore`, i.e. `~/.cache/footballscore`
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| "./".into())
+            .join("footballscore")
+    }
+
+    /// Ignore the cached responses, the fresh ones are still cached
+    #[must_use]
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Url of a request with its query sorted, e.g.
+    /// `https://v3.football.api-sports.io/fixtures?live=all&team=529`. The
+    /// base url keeps the responses of a local stand-in apart from the api's.
+    /// ```
+    /// use footballscore::football_cache::ApiCache;
+    ///
+    /// let base_url = "https://v3.football.api-sports.io/";
+    /// let options = [("team", "529".into()), ("live", "all".into())];
+    /// assert_eq!(
+    ///     ApiCache::key(base_url, "fixtures", &options),
+    ///     "https://v3.football.api-sports.io/fixtures?live=all&team=529"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn key(base_url: &str, command: &str, options: &[(&str, ApiStringType)]) -> StringType {
+        let mut options: Vec<_> = options.iter().collect();
+        options.sort();
+
+        let mut key = StringType::from(base_url);
+        key.push_str(command);
+        for (i, (name, value)) in options.into_iter().enumerate() {
+            let separator = if i == 0 { '?' } else { '&' };
+            let _ = write!(key, "{separator}{name}={value}");
+        }
+        key
+    }
+
+    /// How long the response of a request is served from the cache
+    #[must_use]
+    pub fn ttl(command: &str, options: &[(&str, ApiStringType)]) -> Duration {
+        let has = |option: &str| options.iter().any(|(name, _)| *name == option);
+
+        match command {
+            "teams" => TEAMS_TTL,
+            "standings" => STANDINGS_TTL,
+            "fixtures/headtohead" => HEAD_TO_HEAD_TTL,
+            "fixtures" if has("live") => LIVE_TTL,
+            "fixtures" if has("date") || has("from") => MATCH_DAY_TTL,
+            "fixtures" if has("next") || has("last") => SCHEDULE_TTL,
+            _ => MATCH_DAY_TTL,
+        }
+    }
+
+    fn path(&self, base_url: &str, command: &str, options: &[(&str, ApiStringType)]) -> PathBuf {
+        let digest = Sha256::digest(Self::key(base_url, command, options).as_bytes());
+        let mut name = command.replace('/', "-");
+        name.push('-');
+        for byte in &digest[..8] {
+            let _ = write!(name, "{byte:02x}");
+        }
+        name.push_str(".json");
+        self.dir.join(name)
+    }
+
+    /// Cached response of a request to the api at `base_url`, unless it is
+    /// older than its ttl
+    /// # Errors
+    ///
+    /// Will return error if the cached response cannot be read
+    pub async fn get(
+        &self,
+        base_url: &str,
+        command: &str,
+        options: &[(&str, ApiStringType)],
+    ) -> Result<Option<StringType>, Error> {
+        if self.refresh {
+            return Ok(None);
+        }
+
+        let path = self.path(base_url, command, options);
+        let Ok(metadata) = tokio::fs::metadata(&path).await else {
+            return Ok(None);
+        };
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if age.is_none_or(|age| age >= Self::ttl(command, options)) {
+            return Ok(None);
+        }
+
+        Ok(Some(tokio::fs::read_to_string(&path).await?.into()))
+    }
+
+    /// Cache the response of a request, responses the api reports `errors`
+    /// in (e.g. a bad api key or an exhausted quota) are not cached
+    /// # Errors
+    ///
+    /// Will return error if the response cannot be written
+    pub async fn put(
+        &self,
+        base_url: &str,
+        command: &str,
+        options: &[(&str, ApiStringType)],
+        body: &str,
+    ) -> Result<(), Error> {
+        let has_errors = serde_json::from_str::<serde_json::Value>(body)
+            .map(|value| match &value["errors"] {
+                serde_json::Value::Array(errors) => !errors.is_empty(),
+                serde_json::Value::Object(errors) => !errors.is_empty(),
+                serde_json::Value::Null => false,
+                _ => true,
+            })
+            .unwrap_or(true);
+        if has_errors {
+            return Ok(());
+        }
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let path = self.path(base_url, command, options);
+        // write then rename, a reader never sees half a response
+        let tmp_path = path.with_extension(format_string!("json.{}.tmp", std::process::id()));
+        tokio::fs::write(&tmp_path, body).await?;
+        tokio::fs::rename(tmp_path, path).await?;
+
+        Ok(())
+    }
+
+    /// Rate limit kept by `save_rate_limit`, e.g. by the previous run
+    pub async fn load_rate_limit(&self) -> Option<RateLimit> {
+        let buf = tokio::fs::read(self.dir.join(RATE_LIMIT_FILE)).await.ok()?;
+        serde_json::from_slice(&buf).ok()
+    }
+
+    /// Keep the rate limit of the latest response for the next run
+    /// # Errors
+    ///
+    /// Will return error if the rate limit cannot be written
+    pub async fn save_rate_limit(&self, rate_limit: &RateLimit) -> Result<(), Error> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let path = self.dir.join(RATE_LIMIT_FILE);
+        let tmp_path = path.with_extension(format_string!("json.{}.tmp", std::process::id()));
+        tokio::fs::write(&tmp_path, serde_json::to_vec(rate_limit)?).await?;
+        tokio::fs::rename(tmp_path, path).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use chrono::Utc;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    use crate::{football_cache::ApiCache, football_ratelimit::RateLimit, ApiStringType, Error};
+
+    #[test]
+    fn test_ttl() {
+        let options = |names: &[&'static str]| -> Vec<(&'static str, ApiStringType)> {
+            names.iter().map(|name| (*name, "1".into())).collect()
+        };
+
+        assert_eq!(
+            ApiCache::ttl("teams", &options(&["name"])),
+            Duration::from_secs(7 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            ApiCache::ttl("fixtures", &options(&["team", "live"])),
+            Duration::from_secs(15)
+        );
+        assert_eq!(
+            ApiCache::ttl("fixtures", &options(&["team", "next"])),
+            Duration::from_secs(60 * 60)
+        );
+        assert_eq!(
+            ApiCache::ttl("fixtures", &options(&["league", "date"])),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            ApiCache::ttl("fixtures/events", &options(&["fixture"])),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_cache() -> Result<(), Error> {
+        let cache_dir = tempdir()?;
+        let cache = ApiCache::new(cache_dir.path().join("footballscore"));
+        let options = [("team", "529".into()), ("next", "1".into())];
+        let body = include_str!("../tests/resource/fixtures.json");
+        let base_url = "https://v3.football.api-sports.io/";
+
+        assert_eq!(cache.get(base_url, "fixtures", &options).await?, None);
+        cache.put(base_url, "fixtures", &options, body).await?;
+        assert_eq!(
+            cache.get(base_url, "fixtures", &options).await?,
+            Some(body.into())
+        );
+
+        // the order of the query does not matter, its values do
+        let reordered = [options[1].clone(), options[0].clone()];
+        assert_eq!(
+            cache.get(base_url, "fixtures", &reordered).await?,
+            Some(body.into())
+        );
+        let other = [("team", "42".into()), ("next", "1".into())];
+        assert_eq!(cache.get(base_url, "fixtures", &other).await?, None);
+
+        // nor is a local stand-in answered with the api's responses
+        let stand_in = "http://127.0.0.1:8080/v3/";
+        assert_eq!(cache.get(stand_in, "fixtures", &options).await?, None);
+
+        // `--refresh` skips the cached response
+        let refresh = cache.clone().with_refresh(true);
+        assert_eq!(refresh.get(base_url, "fixtures", &options).await?, None);
+
+        // responses with api errors are not cached
+        let error = r#"{"errors": {"token": "Error/Missing application key."}, "response": []}"#;
+        cache
+            .put(base_url, "teams", &[("name", "x".into())], error)
+            .await?;
+        assert_eq!(
+            cache
+                .get(base_url, "teams", &[("name", "x".into())])
+                .await?,
+            None
+        );
+        assert_eq!(std::fs::read_dir(cache.dir())?.count(), 1);
+
+        // the rate limit of the previous run
+        assert_eq!(cache.load_rate_limit().await, None);
+        let rate_limit = RateLimit {
+            requests_limit: Some(100),
+            requests_remaining: Some(42),
+            minute_limit: None,
+            minute_remaining: None,
+            updated_at: Utc::now(),
+        };
+        cache.save_rate_limit(&rate_limit).await?;
+        assert_eq!(cache.load_rate_limit().await, Some(rate_limit));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,350 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    football_events_data::Event,
+    football_fixtures_data::{FixtureStatus, FootballFixturesData, Response},
+    format_string, StringType,
+};
+
+/// What happened in a fixture between two snapshots
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum ChangeKind {
+    KickOff,
+    Goal {
+        team: StringType,
+    },
+    RedCard {
+        team: StringType,
+        player: Option<StringType>,
+    },
+    HalfTime,
+    FullTime,
+}
+
+/// A change of a fixture together with the score after it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureChange {
+    pub fixture_id: u32,
+    pub home: StringType,
+    pub away: StringType,
+    pub home_goals: usize,
+    pub away_goals: usize,
+
+    /// Minute of the match the change happened in, `null` before kick-off
+    pub minute: Option<u8>,
+
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+impl FixtureChange {
+    fn new(response: &Response, kind: ChangeKind) -> Self {
+        Self {
+            fixture_id: response.fixture.id,
+            home: response.teams.home.name.clone(),
+            away: response.teams.away.name.clone(),
+            home_goals: response.goals.home.unwrap_or_default(),
+            away_goals: response.goals.away.unwrap_or_default(),
+            minute: response.fixture.status.elapsed,
+            kind,
+        }
+    }
+
+    /// Headline of the change, e.g. `Goal for Barcelona`
+    #[must_use]
+    pub fn summary(&self) -> StringType {
+        match &self.kind {
+            ChangeKind::KickOff => "Kick-off".into(),
+            ChangeKind::Goal { team } => format_string!("Goal for {team}"),
+            ChangeKind::RedCard { team, player } => {
+                let mut summary = StringType::from("Red card");
+                if let Some(minute) = self.minute {
+                    summary.push_str(&format_string!(" {minute}'"));
+                }
+                match player {
+                    Some(player) => summary.push_str(&format_string!(": {player} ({team})")),
+                    None => summary.push_str(&format_string!(": {team}")),
+                }
+                summary
+            }
+            ChangeKind::HalfTime => "Half-time".into(),
+            ChangeKind::FullTime => "Full-time".into(),
+        }
+    }
+
+    /// The score after the change, e.g. `Barcelona 1 - 0 Arsenal`
+    #[must_use]
+    pub fn score(&self) -> StringType {
+        format_string!(
+            "{} {} - {} {}",
+            self.home,
+            self.home_goals,
+            self.away_goals,
+            self.away
+        )
+    }
+}
+
+impl fmt::Display for FixtureChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.summary(), self.score())
+    }
+}
+
+/// Started means live or over, i.e. no longer waiting for kick-off
+fn has_started(status: &FixtureStatus) -> bool {
+    status.is_live() || status.is_finished()
+}
+
+fn red_cards(response: &Response) -> impl Iterator<Item = &Event> {
+    response.events.iter().filter(|event| event.is_red_card())
+}
+
+fn find_fixture(data: &FootballFixturesData, fixture_id: u32) -> Option<&Response> {
+    data.response
+        .iter()
+        .find(|response| response.fixture.id == fixture_id)
+}
+
+fn detect_fixture_changes(previous: &Response, current: &Response) -> Vec<FixtureChange> {
+    let mut changes = Vec::new();
+    let before = &previous.fixture.status.short;
+    let after = &current.fixture.status.short;
+
+    if !has_started(before) && has_started(after) {
+        changes.push(FixtureChange::new(current, ChangeKind::KickOff));
+    }
+
+    let home_goals = current.goals.home.unwrap_or_default();
+    let away_goals = current.goals.away.unwrap_or_default();
+
+    // one change per goal, a goal disallowed by VAR is not reported
+    for _ in previous.goals.home.unwrap_or_default()..home_goals {
+        let team = current.teams.home.name.clone();
+        changes.push(FixtureChange::new(current, ChangeKind::Goal { team }));
+    }
+    for _ in previous.goals.away.unwrap_or_default()..away_goals {
+        let team = current.teams.away.name.clone();
+        changes.push(FixtureChange::new(current, ChangeKind::Goal { team }));
+    }
+
+    for event in red_cards(current).skip(red_cards(previous).count()) {
+        let mut change = FixtureChange::new(
+            current,
+            ChangeKind::RedCard {
+                team: event.team.name.clone(),
+                player: event.player.name.clone(),
+            },
+        );
+        change.minute = Some(event.time.elapsed);
+        changes.push(change);
+    }
+
+    if *before != FixtureStatus::HalfTime && *after == FixtureStatus::HalfTime {
+        changes.push(FixtureChange::new(current, ChangeKind::HalfTime));
+    }
+
+    if !before.is_finished() && after.is_finished() {
+        changes.push(FixtureChange::new(current, ChangeKind::FullTime));
+    }
+
+    changes
+}
+
+/// Compare two snapshots of the same fixtures and list what changed,
+/// ordered by kick-off. A live fixture missing from `previous` has just
+/// kicked off, a live one missing from `current` is over, e.g. it dropped
+/// out of the `live=all` query. Nothing changed when the api answered
+/// `current` with errors, its fixtures are missing because of them.
+/// ```
+/// use footballscore::{
+///     football_changes::{detect_changes, ChangeKind},
+///     football_fixtures_data::FootballFixturesData,
+/// };
+/// # use anyhow::Error;
+/// # use std::io::{stdout, Write, Read};
+/// # use std::fs::File;
+/// # fn main() -> Result<(), Error> {
+/// # let mut buf = String::new();
+/// # let mut f = File::open("tests/resource/fixtures.json")?;
+/// # f.read_to_string(&mut buf)?;
+/// let previous: FootballFixturesData = serde_json::from_str(&buf)?;
+/// let mut current = previous.clone();
+/// current.response[0].goals.home = Some(1);
+///
+/// let changes = detect_changes(&previous, &current);
+///
+/// assert_eq!(changes[0].kind, ChangeKind::Goal { team: "Barcelona".into() });
+/// assert_eq!(changes[0].to_string(), "Goal for Barcelona: Barcelona 1 - 1 Arsenal");
+///
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn detect_changes(
+    previous: &FootballFixturesData,
+    current: &FootballFixturesData,
+) -> Vec<FixtureChange> {
+    if current.get_api_errors().is_some() {
+        return Vec::new();
+    }
+
+    let mut fixtures: Vec<(u32, Vec<FixtureChange>)> = current
+        .response
+        .iter()
+        .map(|current| {
+            let changes = match find_fixture(previous, current.fixture.id) {
+                Some(previous) => detect_fixture_changes(previous, current),
+                None if current.fixture.status.short.is_live() => {
+                    vec![FixtureChange::new(current, ChangeKind::KickOff)]
+                }
+                None => Vec::new(),
+            };
+            (current.fixture.timestamp, changes)
+        })
+        .collect();
+
+    // the score is the last one seen while the fixture was live
+    fixtures.extend(
+        previous
+            .response
+            .iter()
+            .filter(|previous| previous.fixture.status.short.is_live())
+            .filter(|previous| find_fixture(current, previous.fixture.id).is_none())
+            .map(|previous| {
+                let change = FixtureChange::new(previous, ChangeKind::FullTime);
+                (previous.fixture.timestamp, vec![change])
+            }),
+    );
+    fixtures.sort_by_key(|(timestamp, _)| *timestamp);
+
+    fixtures
+        .into_iter()
+        .flat_map(|(_, changes)| changes)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_changes::{detect_changes, ChangeKind},
+        football_events_data::FootballEventsData,
+        football_fixtures_data::{FixtureStatus, FootballErrors, FootballFixturesData},
+        Error,
+    };
+
+    #[test]
+    fn test_detect_changes() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let mut previous: FootballFixturesData = serde_json::from_str(buf)?;
+        previous.response[0].fixture.status.short = FixtureStatus::NotStarted;
+        previous.response[0].goals.home = None;
+        previous.response[0].goals.away = None;
+
+        let mut current = previous.clone();
+        assert!(detect_changes(&previous, &current).is_empty());
+
+        current.response[0].fixture.status.short = FixtureStatus::FirstHalf;
+        current.response[0].goals.home = Some(0);
+        current.response[0].goals.away = Some(2);
+        let buf = include_str!("../tests/resource/events.json");
+        let events: FootballEventsData = serde_json::from_str(buf)?;
+        current.response[0].events = events.response;
+
+        let changes = detect_changes(&previous, &current);
+        let kinds: Vec<_> = changes.iter().map(|change| &change.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &ChangeKind::KickOff,
+                &ChangeKind::Goal {
+                    team: "Arsenal".into()
+                },
+                &ChangeKind::Goal {
+                    team: "Arsenal".into()
+                },
+                &ChangeKind::RedCard {
+                    team: "Arsenal".into(),
+                    player: Some("Gabriel".into()),
+                },
+            ]
+        );
+        assert_eq!(
+            changes[3].to_string(),
+            "Red card 40': Gabriel (Arsenal): Barcelona 0 - 2 Arsenal"
+        );
+
+        // nothing new, nothing reported again
+        assert!(detect_changes(&current, &current).is_empty());
+
+        let previous = current.clone();
+        current.response[0].fixture.status.short = FixtureStatus::HalfTime;
+        let changes = detect_changes(&previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::HalfTime);
+
+        let previous = current.clone();
+        current.response[0].fixture.status.short = FixtureStatus::FullTime;
+        let changes = detect_changes(&previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to_string(), "Full-time: Barcelona 0 - 2 Arsenal");
+
+        // a finished fixture first seen in the current snapshot has nothing
+        // to compare with
+        assert!(detect_changes(&FootballFixturesData::default(), &current).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_changes_missing_fixture() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let mut live: FootballFixturesData = serde_json::from_str(buf)?;
+        live.response[0].fixture.status.short = FixtureStatus::FirstHalf;
+        live.response[0].fixture.status.elapsed = Some(3);
+        live.response[0].goals.home = Some(0);
+        live.response[0].goals.away = Some(0);
+        let empty = FootballFixturesData::default();
+
+        // `live=all` only returns a fixture once it has kicked off
+        let changes = detect_changes(&empty, &live);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::KickOff);
+        assert_eq!(changes[0].minute, Some(3));
+
+        // and drops it once it is over
+        live.response[0].fixture.status.elapsed = Some(90);
+        live.response[0].goals.away = Some(1);
+        let changes = detect_changes(&live, &empty);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to_string(), "Full-time: Barcelona 0 - 1 Arsenal");
+
+        // a fixture not seen live before is not reported over
+        let mut scheduled = live.clone();
+        scheduled.response[0].fixture.status.short = FixtureStatus::NotStarted;
+        assert!(detect_changes(&scheduled, &empty).is_empty());
+
+        // changes of several fixtures are ordered by kick-off
+        let mut later = live.response[0].clone();
+        later.fixture.id += 1;
+        later.fixture.timestamp += 3600;
+        let mut previous = live.clone();
+        previous.response[0].fixture.timestamp += 7200;
+        let mut current = empty.clone();
+        current.response.push(later);
+        let changes = detect_changes(&previous, &current);
+        let kinds: Vec<_> = changes.iter().map(|change| &change.kind).collect();
+        assert_eq!(kinds, [&ChangeKind::KickOff, &ChangeKind::FullTime]);
+
+        // an exhausted quota does not end the live fixtures
+        let mut errored = empty.clone();
+        errored.errors = FootballErrors::WithMessages(
+            [("requests".into(), "request limit reached".into())].into(),
+        );
+        assert!(detect_changes(&live, &errored).is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Write};
+
+use crate::{
+    football_fixtures_data::{Paging, Parameters},
+    format_string, StringType,
+};
+
+#[derive(Serialize, Default, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time {
+    pub elapsed: u8,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    pub id: u16,
+    pub name: StringType,
+    pub logo: StringType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Player {
+    pub id: Option<u32>,
+    pub name: Option<StringType>,
+}
+
+/// The `type` of a match event, `subst` is the api name for substitutions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventKind {
+    Goal,
+    Card,
+
+    #[serde(rename = "subst", alias = "Subst")]
+    Substitution,
+
+    #[serde(alias = "VAR")]
+    Var,
+
+    #[default]
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Event {
+    pub time: Time,
+    pub team: Team,
+    pub player: Player,
+    pub assist: Player,
+
+    #[serde(rename = "type")]
+    pub kind: EventKind,
+
+    pub detail: StringType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<StringType>,
+}
+
+impl Event {
+    /// Minute of the event including stoppage time, e.g. `45+2'`
+    #[must_use]
+    pub fn minute(&self) -> StringType {
+        match self.time.extra {
+            Some(extra) => format_string!("{}+{extra}'", self.time.elapsed),
+            None => format_string!("{}'", self.time.elapsed),
+        }
+    }
+
+    /// Straight red or second yellow card
+    #[must_use]
+    pub fn is_red_card(&self) -> bool {
+        self.kind == EventKind::Card
+            && (self.detail.eq_ignore_ascii_case("Red Card")
+                || self.detail.eq_ignore_ascii_case("Second Yellow card"))
+    }
+
+    /// Short description of the event, the api `detail` is only used when it
+    /// adds something to the `type`
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self.kind {
+            EventKind::Goal if self.detail == "Normal Goal" => "Goal",
+            EventKind::Substitution => "Substitution",
+            EventKind::Var => "VAR",
+            _ => &self.detail,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FootballEventsErrors {
+    Empty(Vec<Option<serde_json::Value>>),
+    WithMessages(HashMap<String, String>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootballEventsData {
+    pub get: StringType,
+
+    #[serde(flatten)]
+    pub parameters: Parameters,
+
+    pub errors: FootballEventsErrors,
+    pub results: usize,
+    pub paging: Paging,
+    pub response: Vec<Event>,
+}
+
+impl Default for FootballEventsData {
+    fn default() -> Self {
+        Self {
+            get: "".into(),
+            parameters: Parameters::Fixture("".into()),
+            errors: FootballEventsErrors::Empty(Vec::new()),
+            results: 0,
+            paging: Paging::default(),
+            response: Vec::new(),
+        }
+    }
+}
+
+impl FootballEventsData {
+    /// Events sorted by the minute they happened, the api does not guarantee
+    /// any ordering
+    #[must_use]
+    pub fn get_chronological_events(&self) -> Vec<&Event> {
+        let mut events: Vec<&Event> = self.response.iter().collect();
+        events.sort_by_key(|event| (event.time.elapsed, event.time.extra.unwrap_or_default()));
+        events
+    }
+
+    /// Write out a chronological timeline of the match events for a mutable
+    /// buffer.
+    /// ```
+    /// use footballscore::football_events_data::FootballEventsData;
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/events.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballEventsData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_events_timeline();
+    ///
+    /// assert!(buf.starts_with("Match events:\n"));
+    /// assert!(buf.contains("12'  Arsenal - Goal: B. Saka (assist: M. Ødegaard)"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_events_timeline(&self) -> StringType {
+        let mut output = StringType::from("");
+
+        if !self.response.is_empty() {
+            output.push_str("Match events:\n");
+
+            for event in self.get_chronological_events() {
+                let player = event.player.name.as_ref().map_or("", StringType::as_str);
+
+                let _ = write!(
+                    output,
+                    "{:>6}  {} - {}: ",
+                    event.minute(),
+                    &event.team.name,
+                    event.label()
+                );
+
+                match (event.kind, &event.assist.name) {
+                    (EventKind::Substitution, Some(player_in)) => {
+                        let _ = write!(output, "{player_in} on for {player}");
+                    }
+                    (EventKind::Goal, Some(assist)) => {
+                        let _ = write!(output, "{player} (assist: {assist})");
+                    }
+                    (EventKind::Var, _) => {
+                        let _ = write!(output, "{player} ({})", &event.detail);
+                    }
+                    _ => output.push_str(player),
+                }
+
+                output.push('\n');
+            }
+        } else if let FootballEventsErrors::WithMessages(error_messages) = &self.errors {
+            let mut buffer = String::with_capacity(500);
+
+            let print_error = |output: &mut String, field_name: &str, error: &str| {
+                let _ = writeln!(output, "Error: {field_name} - {error}");
+            };
+
+            for field_name in &["access", "token", "requests", "fixture"] {
+                if let Some(error) = error_messages.get(*field_name) {
+                    print_error(&mut buffer, field_name, error);
+                }
+            }
+
+            if !buffer.is_empty() {
+                output.push_str(&buffer);
+            }
+        } else {
+            output.push_str("Match events: none yet\n");
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_events_data::{
+            EventKind, FootballEventsData, FootballEventsErrors, Paging, Parameters,
+        },
+        Error,
+    };
+    use log::info;
+
+    #[test]
+    fn test_football_events_data() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/events.json");
+        let data: FootballEventsData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::Fixture("239625".into()));
+        assert_eq!(data.response[3].kind, EventKind::Substitution);
+        assert_eq!(data.response[4].kind, EventKind::Var);
+
+        let buf = data.get_events_timeline();
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Match events:",
+                "   12'  Arsenal - Goal: B. Saka (assist: M. Ødegaard)",
+                "   38'  Arsenal - VAR: B. Saka (Goal cancelled)",
+                "   40'  Arsenal - Red Card: Gabriel",
+                "   45'  Barcelona - Missed Penalty: R. Lewandowski",
+                " 45+2'  Barcelona - Yellow Card: Gavi",
+                "   46'  Barcelona - Substitution: Pedri on for Gavi",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_football_data() -> Result<(), Error> {
+        let default_data = FootballEventsData::default();
+
+        assert_eq!(
+            default_data.get,
+            "".to_string(),
+            "Expected default get value"
+        );
+
+        assert_eq!(
+            default_data.parameters,
+            Parameters::Fixture("".into()),
+            "Expected default parameters"
+        );
+
+        if let FootballEventsErrors::Empty(empty_errors) = &default_data.errors {
+            assert!(
+                empty_errors.is_empty(),
+                "Expected no errors in default data"
+            );
+        } else {
+            panic!("Unexpected non-empty errors variant in default data");
+        }
+
+        assert_eq!(default_data.results, 0, "Expected default results value");
+
+        assert_eq!(
+            default_data.paging,
+            Paging::default(),
+            "Expected default paging"
+        );
+
+        assert!(
+            default_data.response.is_empty(),
+            "Expected no response data in default"
+        );
+
+        assert_eq!(
+            default_data.get_events_timeline(),
+            "Match events: none yet\n"
+        );
+
+        Ok(())
+    }
+}
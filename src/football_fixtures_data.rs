@@ -1,8 +1,51 @@
-use chrono::DateTime;
-use serde::{Deserialize, Deserializer, Serialize};
-use std::{collections::HashMap, fmt::Write};
+use chrono::{DateTime, FixedOffset, Local, TimeZone};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    fmt,
+    fmt::Write,
+    str::FromStr,
+};
+
+use crate::{football_events_data::Event, format_string, Error, StringType};
+
+/// Zone the kick-off times are written out in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Timezone {
+    /// Local zone of the system
+    #[default]
+    Local,
+    /// IANA zone, e.g. `Europe/Madrid`
+    Named(Tz),
+}
+
+impl Timezone {
+    #[must_use]
+    pub fn convert<Z: TimeZone>(&self, date: &DateTime<Z>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Local => date.with_timezone(&Local).fixed_offset(),
+            Self::Named(tz) => date.with_timezone(tz).fixed_offset(),
+        }
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = Error;
 
-use crate::StringType;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Self::Local);
+        }
+
+        s.parse().map(Self::Named).map_err(|_| {
+            Error::InvalidInputError(format_string!(
+                "\nERROR: unknown timezone `{s}`, expected an IANA name such as `Europe/Madrid`\n"
+            ))
+        })
+    }
+}
 
 #[derive(Serialize, Default, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Periods {
@@ -20,10 +63,177 @@ pub struct Venue {
     pub city: StringType,
 }
 
+/// Short status code of a fixture as documented by api-football, codes that
+/// are not known yet are kept as `Unknown`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum FixtureStatus {
+    /// `TBD`, the kick-off time is not known yet
+    #[default]
+    TimeToBeDefined,
+    /// `NS`
+    NotStarted,
+    /// `1H`
+    FirstHalf,
+    /// `HT`
+    HalfTime,
+    /// `2H`
+    SecondHalf,
+    /// `ET`
+    ExtraTime,
+    /// `BT`, break before extra time or penalties
+    BreakTime,
+    /// `P`, penalty shoot-out in progress
+    PenaltyInProgress,
+    /// `SUSP`
+    Suspended,
+    /// `INT`
+    Interrupted,
+    /// `FT`
+    FullTime,
+    /// `AET`
+    AfterExtraTime,
+    /// `PEN`
+    Penalties,
+    /// `PST`
+    Postponed,
+    /// `CANC`
+    Cancelled,
+    /// `ABD`
+    Abandoned,
+    /// `AWD`, technical loss
+    Awarded,
+    /// `WO`
+    WalkOver,
+    /// `LIVE`, in progress without more details
+    Live,
+    Unknown(StringType),
+}
+
+impl FixtureStatus {
+    #[must_use]
+    pub fn from_short(short: &str) -> Self {
+        match short {
+            "TBD" => Self::TimeToBeDefined,
+            "NS" => Self::NotStarted,
+            "1H" => Self::FirstHalf,
+            "HT" => Self::HalfTime,
+            "2H" => Self::SecondHalf,
+            "ET" => Self::ExtraTime,
+            "BT" => Self::BreakTime,
+            "P" => Self::PenaltyInProgress,
+            "SUSP" => Self::Suspended,
+            "INT" => Self::Interrupted,
+            "FT" => Self::FullTime,
+            "AET" => Self::AfterExtraTime,
+            "PEN" => Self::Penalties,
+            "PST" => Self::Postponed,
+            "CANC" => Self::Cancelled,
+            "ABD" => Self::Abandoned,
+            "AWD" => Self::Awarded,
+            "WO" => Self::WalkOver,
+            "LIVE" => Self::Live,
+            _ => Self::Unknown(short.into()),
+        }
+    }
+
+    /// Short code as used by the api, e.g. `NS`
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TimeToBeDefined => "TBD",
+            Self::NotStarted => "NS",
+            Self::FirstHalf => "1H",
+            Self::HalfTime => "HT",
+            Self::SecondHalf => "2H",
+            Self::ExtraTime => "ET",
+            Self::BreakTime => "BT",
+            Self::PenaltyInProgress => "P",
+            Self::Suspended => "SUSP",
+            Self::Interrupted => "INT",
+            Self::FullTime => "FT",
+            Self::AfterExtraTime => "AET",
+            Self::Penalties => "PEN",
+            Self::Postponed => "PST",
+            Self::Cancelled => "CANC",
+            Self::Abandoned => "ABD",
+            Self::Awarded => "AWD",
+            Self::WalkOver => "WO",
+            Self::Live => "LIVE",
+            Self::Unknown(short) => short,
+        }
+    }
+
+    /// The fixture has kicked off and is not over, breaks included
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        matches!(
+            self,
+            Self::FirstHalf
+                | Self::HalfTime
+                | Self::SecondHalf
+                | Self::ExtraTime
+                | Self::BreakTime
+                | Self::PenaltyInProgress
+                | Self::Suspended
+                | Self::Interrupted
+                | Self::Live
+        )
+    }
+
+    /// The fixture is over and its result is final
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            Self::FullTime
+                | Self::AfterExtraTime
+                | Self::Penalties
+                | Self::Awarded
+                | Self::WalkOver
+        )
+    }
+
+    #[must_use]
+    pub fn is_postponed(&self) -> bool {
+        matches!(self, Self::Postponed)
+    }
+
+    /// The fixture will not be played (further)
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled | Self::Abandoned)
+    }
+}
+
+impl fmt::Display for FixtureStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for FixtureStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FixtureStatus {
+    fn deserialize<D>(deserializer: D) -> Result<FixtureStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let short: StringType = Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_short(&short))
+    }
+}
+
 #[derive(Serialize, Default, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Status {
     pub long: StringType,
-    pub short: StringType,
+    pub short: FixtureStatus,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub elapsed: Option<u8>,
@@ -143,6 +353,44 @@ pub struct Response {
     pub teams: Teams,
     pub goals: Goals,
     pub score: Score,
+
+    /// Only returned for live fixtures
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<Event>,
+}
+
+impl Response {
+    /// Kick-off of the fixture in the given zone
+    /// # Errors
+    ///
+    /// Will return error if the api date is not RFC 3339
+    pub fn kickoff(&self, timezone: &Timezone) -> Result<DateTime<FixedOffset>, Error> {
+        let date = DateTime::parse_from_rfc3339(&self.fixture.date).map_err(|e| {
+            Error::InvalidValue(format_string!(
+                "invalid kick-off date `{}` of fixture {}: {e}",
+                &self.fixture.date,
+                self.fixture.id
+            ))
+        })?;
+        Ok(timezone.convert(&date))
+    }
+}
+
+/// Home and away goals of a fixture
+pub type FixtureGoals = (Option<usize>, Option<usize>);
+
+/// Fixtures sharing a league and a day
+type FixtureGroup<'a> = ((u16, StringType), Vec<&'a (&'a Response, FixtureGoals)>);
+
+/// Aggregate results of one club over a list of fixtures
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeadToHeadRecord {
+    pub played: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub goals_scored: usize,
+    pub goals_conceded: usize,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -183,6 +431,16 @@ pub enum Parameters {
     Live(StringType),
     Next(StringType),
     Team(StringType),
+    H2h(StringType),
+    Last(StringType),
+    Date(StringType),
+    From(StringType),
+    To(StringType),
+    League(StringType),
+    Season(StringType),
+    Fixture(StringType),
+    Player(StringType),
+    Type(StringType),
 }
 
 impl<'de> Deserialize<'de> for Parameters {
@@ -200,14 +458,24 @@ impl<'de> Deserialize<'de> for Parameters {
                     "live" => Parameters::Live(param_value.as_str().unwrap_or("").into()),
                     "next" => Parameters::Next(param_value.as_str().unwrap_or("").into()),
                     "team" => Parameters::Team(param_value.as_str().unwrap_or("").into()),
-                    _ => return Err(Error::custom(format!("Encountered an issue with parameter naming `{param_name}` in the fixtures data")))
+                    "h2h" => Parameters::H2h(param_value.as_str().unwrap_or("").into()),
+                    "last" => Parameters::Last(param_value.as_str().unwrap_or("").into()),
+                    "date" => Parameters::Date(param_value.as_str().unwrap_or("").into()),
+                    "from" => Parameters::From(param_value.as_str().unwrap_or("").into()),
+                    "to" => Parameters::To(param_value.as_str().unwrap_or("").into()),
+                    "league" => Parameters::League(param_value.as_str().unwrap_or("").into()),
+                    "season" => Parameters::Season(param_value.as_str().unwrap_or("").into()),
+                    "fixture" => Parameters::Fixture(param_value.as_str().unwrap_or("").into()),
+                    "player" => Parameters::Player(param_value.as_str().unwrap_or("").into()),
+                    "type" => Parameters::Type(param_value.as_str().unwrap_or("").into()),
+                    _ => return Err(Error::custom(format!("Encountered an issue with parameter naming `{param_name}` in the api response")))
                 };
                 return Ok(param);
             }
         }
 
         Err(Error::custom(
-            "Invalid JSON structure detected while parsing `Parameters` for fixtures data",
+            "Invalid JSON structure detected while parsing `Parameters` of the api response",
         ))
     }
 }
@@ -233,19 +501,18 @@ impl Default for FootballFixturesData {
 }
 
 impl FootballFixturesData {
-    fn get_goals(&self) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
-        let (home_goals, away_goals): (Vec<Option<usize>>, Vec<Option<usize>>) = self
-            .response
+    /// Home and away goals of every fixture, in the order of `response`
+    fn get_goals(&self) -> Vec<FixtureGoals> {
+        self.response
             .iter()
             .map(|resp| (resp.goals.home, resp.goals.away))
-            .unzip();
-
-        (home_goals, away_goals)
+            .collect()
     }
 
-    /// Write out formatted information about the fixtures for a mutable buffer.
+    /// Write out formatted information about the fixtures for a mutable buffer,
+    /// kick-off times are shown in `timezone`.
     /// ```
-    /// use footballscore::football_fixtures_data::FootballFixturesData;
+    /// use footballscore::football_fixtures_data::{FootballFixturesData, Timezone};
     /// # use anyhow::Error;
     /// # use std::io::{stdout, Write, Read};
     /// # use std::fs::File;
@@ -255,150 +522,681 @@ impl FootballFixturesData {
     /// # f.read_to_string(&mut buf)?;
     /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
     ///
-    /// let buf = data.get_current_fixtures();
+    /// let buf = data.get_current_fixtures(&"Europe/London".parse()?)?;
     ///
     /// assert!(buf.starts_with("Match: Barcelona 0 vs 1 Arsenal"));
     ///
     /// # Ok(())
     /// # }
     /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn get_current_fixtures(&self, timezone: &Timezone) -> Result<StringType, Error> {
+        self.get_fixtures_information(None, timezone)
+    }
+
+    /// Write out every fixture ordered by kick-off, keeping at most `limit` of
+    /// them.
+    ///
+    /// A single fixture is written out in detail, several fixtures are
+    /// written one per line, grouped by league and date.
+    /// ```
+    /// use footballscore::football_fixtures_data::{FootballFixturesData, Timezone};
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/headtohead.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_fixtures_information(Some(2), &"UTC".parse()?)?;
+    ///
+    /// assert!(buf.starts_with("UEFA Champions League (World) - March 31, 2010\n"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn get_fixtures_information(
+        &self,
+        limit: Option<usize>,
+        timezone: &Timezone,
+    ) -> Result<StringType, Error> {
+        let mut output = StringType::from("");
+
+        let mut fixtures: Vec<(&Response, FixtureGoals)> =
+            self.response.iter().zip(self.get_goals()).collect();
+        fixtures.sort_by_key(|(response, _)| response.fixture.timestamp);
+
+        if let Some(limit) = limit {
+            fixtures.truncate(limit);
+        }
+
+        if let [(response, goals)] = fixtures.as_slice() {
+            Self::write_fixture(&mut output, response, *goals, timezone)?;
+        } else if !fixtures.is_empty() {
+            Self::write_fixture_groups(&mut output, &fixtures, timezone)?;
+        } else if let FootballErrors::WithMessages(error_messages) = &self.errors {
+            output.push_str(&Self::get_error_messages(error_messages));
+        } else {
+            let _ = write!(output, "Match: no live event");
+        }
+
+        Ok(output)
+    }
+
+    /// Errors the api answered with instead of the fixtures, e.g. an
+    /// exhausted quota or a bad key, as `field: message` ordered by field.
+    /// `None` when the response holds the fixtures.
+    /// ```
+    /// use footballscore::football_fixtures_data::FootballFixturesData;
+    /// # use anyhow::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let buf = r#"{
+    ///     "get": "fixtures",
+    ///     "parameters": {"team": "529"},
+    ///     "errors": {"requests": "You have reached the request limit for the day"},
+    ///     "results": 0,
+    ///     "paging": {"current": 1, "total": 1},
+    ///     "response": []
+    /// }"#;
+    /// let data: FootballFixturesData = serde_json::from_str(buf)?;
+    ///
+    /// assert_eq!(
+    ///     data.get_api_errors().unwrap_or_default(),
+    ///     "requests: You have reached the request limit for the day"
+    /// );
+    /// assert_eq!(FootballFixturesData::default().get_api_errors(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
-    pub fn get_current_fixtures(&self) -> StringType {
+    pub fn get_api_errors(&self) -> Option<StringType> {
+        let FootballErrors::WithMessages(error_messages) = &self.errors else {
+            return None;
+        };
+        if error_messages.is_empty() {
+            return None;
+        }
+
+        let mut fields: Vec<_> = error_messages.iter().collect();
+        fields.sort();
+
         let mut output = StringType::from("");
+        for (i, (field, message)) in fields.into_iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            let _ = write!(output, "{separator}{field}: {message}");
+        }
+        Some(output)
+    }
 
-        if let Some(response) = self.response.first() {
-            output.push_str("Match: ");
+    /// Fixture with the earliest kick-off
+    #[must_use]
+    pub fn get_earliest_fixture(&self) -> Option<&Response> {
+        self.response
+            .iter()
+            .min_by_key(|response| response.fixture.timestamp)
+    }
 
-            let (home_goals, away_goals) = self.get_goals();
-            let home_team_name = &response.teams.home.name;
+    /// Fixture with the most recent kick-off
+    #[must_use]
+    pub fn get_latest_fixture(&self) -> Option<&Response> {
+        self.response
+            .iter()
+            .max_by_key(|response| response.fixture.timestamp)
+    }
 
-            if let Some(home_score) = home_goals.first().copied() {
+    /// Write out the finished fixtures, most recent first, with the final
+    /// score, half-time score and competition.
+    /// ```
+    /// use footballscore::football_fixtures_data::{FootballFixturesData, Timezone};
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/results.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_results(&"UTC".parse()?)?;
+    ///
+    /// assert!(buf.starts_with("Last 2 results:\n2023-08-09  Arsenal 3 vs 5 Barcelona (HT 2-2)"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn get_results(&self, timezone: &Timezone) -> Result<StringType, Error> {
+        let mut output = StringType::from("");
+
+        if !self.response.is_empty() {
+            let _ = writeln!(output, "Last {} results:", self.response.len());
+
+            let mut results: Vec<&Response> = self.response.iter().collect();
+            results.sort_by_key(|response| Reverse(response.fixture.timestamp));
+
+            for response in results {
+                let date = response.kickoff(timezone)?.format("%Y-%m-%d");
                 let _ = write!(
                     output,
-                    "{} {:?}",
-                    home_team_name,
-                    home_score.unwrap_or_default()
+                    "{date}  {} {} vs {} {}",
+                    &response.teams.home.name,
+                    response.goals.home.unwrap_or_default(),
+                    response.goals.away.unwrap_or_default(),
+                    &response.teams.away.name,
                 );
-            } else {
-                let _ = write!(output, "{home_team_name}");
+
+                let halftime = &response.score.halftime;
+                if let (Some(home), Some(away)) = (halftime.home, halftime.away) {
+                    let _ = write!(output, " (HT {home}-{away})");
+                }
+
+                let _ = writeln!(
+                    output,
+                    ", {} - {}",
+                    &response.league.name, &response.league.round
+                );
+            }
+        } else if let FootballErrors::WithMessages(error_messages) = &self.errors {
+            output.push_str(&Self::get_error_messages(error_messages));
+        } else {
+            output.push_str("Results: no finished matches\n");
+        }
+
+        Ok(output)
+    }
+
+    fn write_fixture(
+        output: &mut StringType,
+        response: &Response,
+        (home_goals, away_goals): FixtureGoals,
+        timezone: &Timezone,
+    ) -> Result<(), Error> {
+        let _ = write!(
+            output,
+            "Match: {} {} vs {} {}",
+            &response.teams.home.name,
+            home_goals.unwrap_or_default(),
+            away_goals.unwrap_or_default(),
+            &response.teams.away.name
+        );
+
+        let date = response.kickoff(timezone)?.format("%B %d, %Y at %I:%M %p");
+        let status = &response.fixture.status;
+
+        if status.short.is_live() {
+            let _ = write!(output, "\nLive: {}", &status.long);
+            if let Some(elapsed) = status.elapsed {
+                let _ = write!(output, " ({elapsed}')");
+            }
+            output.push('\n');
+        } else if status.short.is_finished() {
+            let _ = writeln!(output, "\n{} on {date}", &status.long);
+        } else if status.short.is_postponed() || status.short.is_cancelled() {
+            let _ = writeln!(output, "\n{}, was scheduled on {date}", &status.long);
+        } else {
+            let _ = writeln!(output, "\nNext match on {date}");
+        }
+
+        let _ = write!(
+            output,
+            "\tLeague: {} - {}/{}",
+            &response.league.name, &response.league.season, &response.league.round
+        );
+        let _ = write!(
+            output,
+            "\n\tVenue: {}, {}",
+            &response.fixture.venue.name, &response.fixture.venue.city
+        );
+        let _ = write!(output, "\n\tHome team: {}", &response.teams.home.name);
+        let _ = write!(output, "\n\tAway team: {}", &response.teams.away.name);
+
+        output.push('\n');
+        Ok(())
+    }
+
+    fn write_fixture_groups(
+        output: &mut StringType,
+        fixtures: &[(&Response, FixtureGoals)],
+        timezone: &Timezone,
+    ) -> Result<(), Error> {
+        // fixtures are sorted by kick-off, groups keep the order they first appear in
+        let mut groups: Vec<FixtureGroup> = Vec::new();
+
+        for fixture in fixtures {
+            let (response, _) = fixture;
+            let day = format_string!("{}", response.kickoff(timezone)?.format("%B %d, %Y"));
+            let key = (response.league.id, day);
+
+            match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+                Some((_, group)) => group.push(fixture),
+                None => groups.push((key, vec![fixture])),
+            }
+        }
+
+        for (idx, ((_, day), group)) in groups.into_iter().enumerate() {
+            if idx > 0 {
+                output.push('\n');
             }
 
-            output.push_str(" vs ");
+            if let Some((response, _)) = group.first() {
+                let _ = writeln!(
+                    output,
+                    "{} ({}) - {day}",
+                    &response.league.name, &response.league.country
+                );
+            }
+
+            for (response, (home_goals, away_goals)) in group {
+                let kickoff = response.kickoff(timezone)?.format("%I:%M %p");
+                let _ = write!(output, "  {kickoff}  {}", &response.teams.home.name);
+
+                if let (Some(home_goals), Some(away_goals)) = (home_goals, away_goals) {
+                    let _ = write!(output, " {home_goals} vs {away_goals} ");
+                } else {
+                    output.push_str(" vs ");
+                }
 
-            if let Some(away_score) = away_goals.first().copied() {
                 let _ = write!(
                     output,
-                    "{:?} {}",
-                    away_score.unwrap_or_default(),
-                    &response.teams.away.name
+                    "{} ({}",
+                    &response.teams.away.name, &response.fixture.status.short
                 );
+                if let (true, Some(elapsed)) = (
+                    response.fixture.status.short.is_live(),
+                    response.fixture.status.elapsed,
+                ) {
+                    let _ = write!(output, " {elapsed}'");
+                }
+                output.push_str(")\n");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_error_messages(error_messages: &HashMap<String, String>) -> String {
+        let mut buffer = String::with_capacity(500);
+
+        let print_error = |output: &mut String, field_name: &str, error: &str| {
+            writeln!(output, "Error: {field_name} - {error}").unwrap_or_default();
+        };
+
+        for field_name in &[
+            "access", "token", "requests", "h2h", "date", "from", "to", "league", "season", "team",
+        ] {
+            if let Some(error) = error_messages.get(*field_name) {
+                print_error(&mut buffer, field_name, error);
+            }
+        }
+
+        buffer
+    }
+
+    fn get_team_name(&self, team_id: u16) -> Option<&StringType> {
+        self.response.iter().find_map(|response| {
+            if response.teams.home.id == team_id {
+                Some(&response.teams.home.name)
+            } else if response.teams.away.id == team_id {
+                Some(&response.teams.away.name)
             } else {
-                let _ = write!(output, "{}", &response.teams.away.name);
+                None
             }
+        })
+    }
 
-            let _ = write!(
-                output,
-                "\nNext match on {}\n",
-                DateTime::parse_from_rfc3339(&response.fixture.date)
-                    .unwrap_or_default()
-                    .format("%B %d, %Y at %I:%M %p")
-            );
+    /// Aggregate results of `team_id` over the fixtures that have a score
+    #[must_use]
+    pub fn get_head_to_head_record(&self, team_id: u16) -> HeadToHeadRecord {
+        let mut record = HeadToHeadRecord::default();
 
-            let _ = write!(
-                output,
-                "\tLeague: {} - {}/{}",
-                &response.league.name, &response.league.season, &response.league.round
-            );
-            let _ = write!(
+        for response in &self.response {
+            let (Some(home_goals), Some(away_goals)) = (response.goals.home, response.goals.away)
+            else {
+                continue;
+            };
+
+            let (scored, conceded) = if response.teams.home.id == team_id {
+                (home_goals, away_goals)
+            } else if response.teams.away.id == team_id {
+                (away_goals, home_goals)
+            } else {
+                continue;
+            };
+
+            record.played += 1;
+            record.goals_scored += scored;
+            record.goals_conceded += conceded;
+
+            match scored.cmp(&conceded) {
+                Ordering::Greater => record.wins += 1,
+                Ordering::Equal => record.draws += 1,
+                Ordering::Less => record.losses += 1,
+            }
+        }
+
+        record
+    }
+
+    /// Write out the meetings between two clubs, most recent first, followed
+    /// by the aggregate record of each side.
+    /// ```
+    /// use footballscore::football_fixtures_data::{FootballFixturesData, Timezone};
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/headtohead.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_head_to_head(529, 33, &Timezone::default())?;
+    ///
+    /// assert!(buf.starts_with("Head to head: Barcelona vs Arsenal, last 3 meetings"));
+    /// assert!(buf.contains("Barcelona: 2 W, 1 D, 0 L, 11 goals"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a meeting is malformed
+    pub fn get_head_to_head(
+        &self,
+        first_id: u16,
+        second_id: u16,
+        timezone: &Timezone,
+    ) -> Result<StringType, Error> {
+        let mut output = StringType::from("");
+
+        if !self.response.is_empty() {
+            let first_name = self.get_team_name(first_id).map_or("", StringType::as_str);
+            let second_name = self.get_team_name(second_id).map_or("", StringType::as_str);
+
+            let _ = writeln!(
                 output,
-                "\n\tVenue: {}, {}",
-                &response.fixture.venue.name, &response.fixture.venue.city
+                "Head to head: {first_name} vs {second_name}, last {} meetings",
+                self.response.len()
             );
-            let _ = write!(output, "\n\tHome team: {}", &response.teams.home.name);
-            let _ = write!(output, "\n\tAway team: {}", &response.teams.away.name);
 
-            output.push('\n');
-        } else if let FootballErrors::WithMessages(error_messages) = &self.errors {
-            let mut buffer = String::with_capacity(500);
+            let mut meetings: Vec<&Response> = self.response.iter().collect();
+            meetings.sort_by_key(|response| Reverse(response.fixture.timestamp));
 
-            let print_error = |output: &mut String, field_name: &str, error: &str| {
-                writeln!(output, "Error: {field_name} - {error}").unwrap_or_default();
-            };
+            for response in meetings {
+                let date = response.kickoff(timezone)?.format("%Y-%m-%d");
+                let _ = write!(output, "{date}  {}", &response.teams.home.name);
 
-            for field_name in &["access", "token", "requests"] {
-                if let Some(error) = error_messages.get(*field_name) {
-                    print_error(&mut buffer, field_name, error);
+                if let (Some(home_goals), Some(away_goals)) =
+                    (response.goals.home, response.goals.away)
+                {
+                    let _ = write!(output, " {home_goals} vs {away_goals} ");
+                } else {
+                    output.push_str(" vs ");
                 }
+
+                let _ = writeln!(
+                    output,
+                    "{} ({})",
+                    &response.teams.away.name, &response.league.name
+                );
             }
 
-            if !buffer.is_empty() {
-                output.push_str(&buffer);
+            for (team_id, team_name) in [(first_id, first_name), (second_id, second_name)] {
+                let record = self.get_head_to_head_record(team_id);
+                let _ = writeln!(
+                    output,
+                    "{team_name}: {} W, {} D, {} L, {} goals",
+                    record.wins, record.draws, record.losses, record.goals_scored
+                );
             }
+        } else if let FootballErrors::WithMessages(error_messages) = &self.errors {
+            output.push_str(&Self::get_error_messages(error_messages));
         } else {
-            let _ = write!(output, "Match: no live event");
+            output.push_str("Head to head: no previous meetings\n");
         }
 
-        output
+        Ok(output)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        football_fixtures_data::{FootballErrors, FootballFixturesData, Paging, Parameters},
+        football_fixtures_data::{
+            FixtureStatus, FootballErrors, FootballFixturesData, HeadToHeadRecord, Paging,
+            Parameters, Timezone,
+        },
         Error,
     };
     use log::info;
 
+    const UTC: Timezone = Timezone::Named(chrono_tz::UTC);
+
     #[test]
     fn test_football_data() -> Result<(), Error> {
         let buf = include_str!("../tests/resource/fixtures.json");
         let data: FootballFixturesData = serde_json::from_str(buf)?;
 
-        let buf = data.get_current_fixtures();
+        let buf = data.get_current_fixtures(&UTC)?;
 
-        assert!(buf.starts_with("Match: Barcelona 0 vs 1 Arsenal"));
+        assert!(buf.starts_with("Match: Barcelona 0 vs 1 Arsenal\nLive: Halftime (45')\n"));
+        assert_eq!(
+            data.response[0].fixture.status.short,
+            FixtureStatus::HalfTime
+        );
 
         if let Some(response) = data.response.first() {
-            let (home_goals, away_goals) = data.get_goals();
+            let goals = data.get_goals();
             let home_team_name = &response.teams.home.name;
             let away_team_name = &response.teams.away.name;
 
-            if let Some(home_score) = home_goals.first().copied() {
-                if let Some(away_score) = away_goals.first().copied() {
-                    info!(
-                        "{}: {} {:?} vs {} {:?}",
-                        buf.len(),
-                        home_team_name,
-                        home_score.unwrap_or_default(),
-                        away_team_name,
-                        away_score.unwrap_or_default()
-                    );
-                } else {
-                    info!(
-                        "{}: {} {:?} vs {}",
-                        buf.len(),
-                        home_team_name,
-                        home_score.unwrap_or_default(),
-                        away_team_name
-                    );
-                }
-            } else if let Some(away_score) = away_goals.first().copied() {
+            assert_eq!(goals, vec![(Some(0), Some(1))]);
+
+            if let Some((home_score, away_score)) = goals.first().copied() {
                 info!(
-                    "{}: {} vs {} {:?}",
+                    "{}: {} {:?} vs {} {:?}",
                     buf.len(),
                     home_team_name,
+                    home_score.unwrap_or_default(),
                     away_team_name,
                     away_score.unwrap_or_default()
                 );
-            } else {
-                info!("{}: {} vs {}", buf.len(), home_team_name, away_team_name);
             }
         }
 
         Ok(())
     }
 
+    #[test]
+    fn test_fixtures_information() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        let buf = data.get_fixtures_information(None, &UTC)?;
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "UEFA Champions League (World) - March 31, 2010",
+                "  06:45 PM  Arsenal 2 vs 2 Barcelona (FT)",
+                "",
+                "UEFA Champions League (World) - April 06, 2010",
+                "  06:45 PM  Barcelona 4 vs 1 Arsenal (FT)",
+                "",
+                "Friendlies Clubs (World) - August 09, 2023",
+                "  02:30 AM  Arsenal 3 vs 5 Barcelona (FT)",
+            ]
+        );
+
+        let buf = data.get_fixtures_information(Some(1), &UTC)?;
+        assert!(buf.starts_with(
+            "Match: Arsenal 2 vs 2 Barcelona\nMatch Finished on March 31, 2010 at 06:45 PM\n"
+        ));
+
+        let buf = data.get_fixtures_information(Some(0), &UTC)?;
+        assert_eq!(buf, "Match: no live event");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_results() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/results.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::Last("2".into()));
+        assert_eq!(
+            data.get_latest_fixture().map(|r| r.fixture.id),
+            Some(1_049_000)
+        );
+
+        let buf = data.get_results(&UTC)?;
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Last 2 results:",
+                "2023-08-09  Arsenal 3 vs 5 Barcelona (HT 2-2), Friendlies Clubs - Club Friendlies 3",
+                "2010-04-06  Barcelona 4 vs 1 Arsenal (HT 1-1), UEFA Champions League - Quarter-finals",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timezone() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        assert!("Mars/Olympus_Mons".parse::<Timezone>().is_err());
+        assert_eq!("local".parse::<Timezone>()?, Timezone::Local);
+
+        let madrid: Timezone = "Europe/Madrid".parse()?;
+        let buf = data.get_fixtures_information(Some(2), &madrid)?;
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(lines[1], "  08:45 PM  Arsenal 2 vs 2 Barcelona (FT)");
+
+        // late kick-off moves to the previous day west of UTC
+        let buf = data.get_results(&"America/New_York".parse()?)?;
+        assert!(buf.contains("2023-08-08  Arsenal 3 vs 5 Barcelona"));
+
+        data.response[0].fixture.date = "09/08/2023 02:30".into();
+        assert!(matches!(
+            data.get_fixtures_information(None, &madrid),
+            Err(Error::InvalidValue(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture_status() -> Result<(), Error> {
+        let codes = [
+            "TBD", "NS", "1H", "HT", "2H", "ET", "BT", "P", "SUSP", "INT", "FT", "AET", "PEN",
+            "PST", "CANC", "ABD", "AWD", "WO", "LIVE",
+        ];
+        for code in codes {
+            let status = FixtureStatus::from_short(code);
+            assert_eq!(status.as_str(), code);
+            assert!(!matches!(status, FixtureStatus::Unknown(_)));
+        }
+
+        let status: FixtureStatus = serde_json::from_str(r#""PEN""#)?;
+        assert_eq!(status, FixtureStatus::Penalties);
+        assert!(status.is_finished() && !status.is_live());
+        assert_eq!(serde_json::to_string(&status)?, r#""PEN""#);
+
+        let status: FixtureStatus = serde_json::from_str(r#""XYZ""#)?;
+        assert_eq!(status, FixtureStatus::Unknown("XYZ".into()));
+        assert_eq!(status.to_string(), "XYZ");
+
+        assert!(FixtureStatus::HalfTime.is_live());
+        assert!(FixtureStatus::Postponed.is_postponed());
+        assert!(!FixtureStatus::NotStarted.is_live());
+        assert!(!FixtureStatus::NotStarted.is_finished());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_errors() -> Result<(), Error> {
+        let buf = r#"{
+            "get": "fixtures",
+            "parameters": {"date": "2024-19-10", "league": "140", "season": "2024"},
+            "errors": {"date": "The Date field must contain a valid date (YYYY-MM-DD)."},
+            "results": 0,
+            "paging": {"current": 1, "total": 1},
+            "response": []
+        }"#;
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::Date("2024-19-10".into()));
+        assert_eq!(
+            data.get_fixtures_information(None, &UTC)?,
+            "Error: date - The Date field must contain a valid date (YYYY-MM-DD).\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_head_to_head() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::H2h("529-33".into()));
+
+        let record = data.get_head_to_head_record(529);
+        assert_eq!(
+            record,
+            HeadToHeadRecord {
+                played: 3,
+                wins: 2,
+                draws: 1,
+                losses: 0,
+                goals_scored: 11,
+                goals_conceded: 6,
+            }
+        );
+
+        let buf = data.get_head_to_head(529, 33, &UTC)?;
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Head to head: Barcelona vs Arsenal, last 3 meetings",
+                "2023-08-09  Arsenal 3 vs 5 Barcelona (Friendlies Clubs)",
+                "2010-04-06  Barcelona 4 vs 1 Arsenal (UEFA Champions League)",
+                "2010-03-31  Arsenal 2 vs 2 Barcelona (UEFA Champions League)",
+                "Barcelona: 2 W, 1 D, 0 L, 11 goals",
+                "Arsenal: 0 W, 1 D, 2 L, 6 goals",
+            ]
+        );
+
+        assert_eq!(
+            FootballFixturesData::default().get_head_to_head(529, 33, &UTC)?,
+            "Head to head: no previous meetings\n"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_default_football_data() -> Result<(), Error> {
         let default_data = FootballFixturesData::default();
@@ -0,0 +1,249 @@
+use crate::{
+    football_fixtures_data::{FixtureStatus, FootballFixturesData, Response},
+    format_string, Error, StringType,
+};
+use chrono::{DateTime, Utc};
+
+/// Length of a calendar event: 90 minutes, half-time and stoppage time
+const ESTIMATED_DURATION: &str = "PT1H55M";
+
+/// Lines longer than this many octets are folded (RFC 5545 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Escape a TEXT value (RFC 5545 3.3.11)
+fn escape_text(text: &str) -> StringType {
+    let mut escaped = StringType::from("");
+
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Write a content line, folded so that no line exceeds `MAX_LINE_OCTETS`
+fn write_line(output: &mut StringType, line: &str) {
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            // the leading space of the continuation counts towards its length
+            output.push_str("\r\n ");
+            octets = 1;
+        }
+        output.push(c);
+        octets += c.len_utf8();
+    }
+
+    output.push_str("\r\n");
+}
+
+fn write_event(
+    output: &mut StringType,
+    response: &Response,
+    dtstamp: &DateTime<Utc>,
+) -> Result<(), Error> {
+    let fixture = &response.fixture;
+    let start = DateTime::from_timestamp(i64::from(fixture.timestamp), 0).ok_or_else(|| {
+        Error::InvalidValue(format_string!(
+            "invalid kick-off timestamp `{}` of fixture {}",
+            fixture.timestamp,
+            fixture.id
+        ))
+    })?;
+
+    let status = if fixture.status.short.is_cancelled() {
+        "CANCELLED"
+    } else if fixture.status.short == FixtureStatus::TimeToBeDefined
+        || fixture.status.short.is_postponed()
+    {
+        "TENTATIVE"
+    } else {
+        "CONFIRMED"
+    };
+
+    let mut location = fixture.venue.name.clone();
+    if !fixture.venue.city.is_empty() {
+        if !location.is_empty() {
+            location.push_str(", ");
+        }
+        location.push_str(&fixture.venue.city);
+    }
+
+    write_line(output, "BEGIN:VEVENT");
+    write_line(
+        output,
+        &format_string!("UID:fixture-{}@footballscore", fixture.id),
+    );
+    write_line(
+        output,
+        &format_string!("DTSTAMP:{}", dtstamp.format(DATE_TIME_FORMAT)),
+    );
+    write_line(
+        output,
+        &format_string!("DTSTART:{}", start.format(DATE_TIME_FORMAT)),
+    );
+    write_line(output, &format_string!("DURATION:{ESTIMATED_DURATION}"));
+    write_line(
+        output,
+        &format_string!(
+            "SUMMARY:{}",
+            escape_text(&format_string!(
+                "{} vs {}",
+                &response.teams.home.name,
+                &response.teams.away.name
+            ))
+        ),
+    );
+    if !location.is_empty() {
+        write_line(
+            output,
+            &format_string!("LOCATION:{}", escape_text(&location)),
+        );
+    }
+    write_line(
+        output,
+        &format_string!(
+            "DESCRIPTION:{}",
+            escape_text(&format_string!(
+                "{} ({}) - {}",
+                &response.league.name,
+                &response.league.country,
+                &response.league.round
+            ))
+        ),
+    );
+    write_line(output, &format_string!("STATUS:{status}"));
+    write_line(output, "END:VEVENT");
+
+    Ok(())
+}
+
+/// Write out the fixtures as an iCalendar, one `VEVENT` per fixture.
+///
+/// The `UID` of an event is derived from the fixture id so that importing
+/// the calendar again updates the events instead of duplicating them.
+/// `dtstamp` is the time the calendar is created.
+/// ```
+/// use footballscore::{football_fixtures_data::FootballFixturesData, football_ics::get_calendar};
+/// # use anyhow::Error;
+/// # use std::io::{stdout, Write, Read};
+/// # use std::fs::File;
+/// # fn main() -> Result<(), Error> {
+/// # let mut buf = String::new();
+/// # let mut f = File::open("tests/resource/fixtures.json")?;
+/// # f.read_to_string(&mut buf)?;
+/// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+///
+/// let buf = get_calendar(&data, &chrono::Utc::now())?;
+///
+/// assert!(buf.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(buf.contains("UID:fixture-239625@footballscore\r\n"));
+///
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+///
+/// Will return error if the timestamp of a fixture is out of range
+pub fn get_calendar(
+    data: &FootballFixturesData,
+    dtstamp: &DateTime<Utc>,
+) -> Result<StringType, Error> {
+    let mut output = StringType::from("");
+
+    write_line(&mut output, "BEGIN:VCALENDAR");
+    write_line(&mut output, "VERSION:2.0");
+    write_line(
+        &mut output,
+        &format_string!(
+            "PRODID:-//footballscore//footballscore {}//EN",
+            env!("CARGO_PKG_VERSION")
+        ),
+    );
+    write_line(&mut output, "CALSCALE:GREGORIAN");
+    write_line(&mut output, "METHOD:PUBLISH");
+
+    let mut fixtures: Vec<&Response> = data.response.iter().collect();
+    fixtures.sort_by_key(|response| response.fixture.timestamp);
+
+    for response in fixtures {
+        write_event(&mut output, response, dtstamp)?;
+    }
+
+    write_line(&mut output, "END:VCALENDAR");
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::{
+        football_fixtures_data::{FixtureStatus, FootballFixturesData},
+        football_ics::{escape_text, get_calendar, write_line},
+        Error, StringType,
+    };
+
+    #[test]
+    fn test_get_calendar() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+        data.response[0].fixture.status.short = FixtureStatus::Postponed;
+
+        let dtstamp = DateTime::from_timestamp(1_729_000_000, 0).unwrap_or_default();
+        let buf = get_calendar(&data, &dtstamp)?;
+
+        assert!(buf.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//footballscore//"));
+        assert!(buf.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(buf.matches("BEGIN:VEVENT").count(), 3);
+
+        let events: Vec<&str> = buf.split("BEGIN:VEVENT\r\n").skip(1).collect();
+        assert_eq!(
+            events[0],
+            "UID:fixture-1035000@footballscore\r\n\
+             DTSTAMP:20241015T134640Z\r\n\
+             DTSTART:20100331T184500Z\r\n\
+             DURATION:PT1H55M\r\n\
+             SUMMARY:Arsenal vs Barcelona\r\n\
+             LOCATION:Emirates Stadium\\, London\r\n\
+             DESCRIPTION:UEFA Champions League (World) - Quarter-finals\r\n\
+             STATUS:CONFIRMED\r\n\
+             END:VEVENT\r\n"
+        );
+        assert!(events[2].contains("STATUS:TENTATIVE\r\n"));
+
+        // same fixtures, same events
+        assert_eq!(get_calendar(&data, &dtstamp)?, buf);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_and_fold() -> Result<(), Error> {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let mut output = StringType::from("");
+        let line = format!("DESCRIPTION:{}", "é".repeat(40));
+        write_line(&mut output, &line);
+
+        let lines: Vec<&str> = output.split("\r\n").collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= 75));
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[0].to_string() + &lines[1][1..], line);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,404 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+};
+
+use crate::{
+    football_fixtures_data::{Paging, Parameters, Teams},
+    format_string, StringType,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Kit {
+    pub primary: Option<StringType>,
+    pub number: Option<StringType>,
+    pub border: Option<StringType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Colors {
+    pub player: Option<Kit>,
+    pub goalkeeper: Option<Kit>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    pub id: u16,
+    pub name: StringType,
+    pub logo: StringType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<Colors>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coach {
+    pub id: Option<u32>,
+    pub name: Option<StringType>,
+    pub photo: Option<StringType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Player {
+    pub id: Option<u32>,
+    pub name: StringType,
+    pub number: Option<u8>,
+    pub pos: Option<StringType>,
+
+    /// Position on the pitch as `row:column`, row `1` is the goalkeeper
+    pub grid: Option<StringType>,
+}
+
+impl Player {
+    fn grid_position(&self) -> Option<(u8, u8)> {
+        let (row, column) = self.grid.as_ref()?.split_once(':')?;
+        Some((row.parse().ok()?, column.parse().ok()?))
+    }
+
+    fn label(&self) -> StringType {
+        match self.number {
+            Some(number) => format_string!("{number} {}", &self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineupPlayer {
+    pub player: Player,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lineup {
+    pub team: Team,
+    pub coach: Coach,
+    pub formation: Option<StringType>,
+
+    #[serde(rename = "startXI")]
+    pub start_xi: Vec<LineupPlayer>,
+
+    pub substitutes: Vec<LineupPlayer>,
+}
+
+impl Lineup {
+    /// Starting XI split into the rows of the formation, goalkeeper first.
+    ///
+    /// The `grid` of each player is used when the api provides it, otherwise
+    /// the players are split in order following the `formation` string.
+    #[must_use]
+    pub fn formation_rows(&self) -> Vec<Vec<&Player>> {
+        let players: Vec<&Player> = self.start_xi.iter().map(|p| &p.player).collect();
+
+        if players.iter().all(|p| p.grid_position().is_some()) {
+            let mut rows: BTreeMap<u8, Vec<(u8, &Player)>> = BTreeMap::new();
+
+            for player in players {
+                if let Some((row, column)) = player.grid_position() {
+                    rows.entry(row).or_default().push((column, player));
+                }
+            }
+
+            return rows
+                .into_values()
+                .map(|mut row| {
+                    row.sort_by_key(|(column, _)| *column);
+                    row.into_iter().map(|(_, player)| player).collect()
+                })
+                .collect();
+        }
+
+        let mut sizes = vec![1];
+        if let Some(formation) = &self.formation {
+            sizes.extend(
+                formation
+                    .split('-')
+                    .filter_map(|s| s.trim().parse::<usize>().ok()),
+            );
+        }
+
+        let mut rows = Vec::new();
+        let mut remaining = players.as_slice();
+
+        for size in sizes {
+            if remaining.is_empty() {
+                break;
+            }
+            let (row, rest) = remaining.split_at(size.min(remaining.len()));
+            rows.push(row.to_vec());
+            remaining = rest;
+        }
+
+        if !remaining.is_empty() {
+            rows.push(remaining.to_vec());
+        }
+
+        rows
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FootballLineupsErrors {
+    Empty(Vec<Option<serde_json::Value>>),
+    WithMessages(HashMap<String, String>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootballLineupsData {
+    pub get: StringType,
+
+    #[serde(flatten)]
+    pub parameters: Parameters,
+
+    pub errors: FootballLineupsErrors,
+    pub results: usize,
+    pub paging: Paging,
+    pub response: Vec<Lineup>,
+}
+
+impl Default for FootballLineupsData {
+    fn default() -> Self {
+        Self {
+            get: "".into(),
+            parameters: Parameters::Fixture("".into()),
+            errors: FootballLineupsErrors::Empty(Vec::new()),
+            results: 0,
+            paging: Paging::default(),
+            response: Vec::new(),
+        }
+    }
+}
+
+impl FootballLineupsData {
+    /// Lineup of the given team, if the api returned one
+    #[must_use]
+    pub fn get_lineup(&self, team_id: u16) -> Option<&Lineup> {
+        self.response
+            .iter()
+            .find(|lineup| lineup.team.id == team_id)
+    }
+
+    /// Write out the formation, substitutes and coach of both sides of the
+    /// fixture, home team first.
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData, football_lineups_data::FootballLineupsData,
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let fixtures: FootballFixturesData = serde_json::from_str(&buf)?;
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/lineups.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballLineupsData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_lineups_information(&fixtures.response[0].teams);
+    ///
+    /// assert!(buf.starts_with("Home: Barcelona (4-3-3)\nCoach: Xavi\n"));
+    /// assert!(buf.contains("Away: Arsenal (4-2-3-1)"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_lineups_information(&self, teams: &Teams) -> StringType {
+        let mut output = StringType::from("");
+
+        if !self.response.is_empty() {
+            let mut lineups: Vec<(&str, &Lineup)> = self
+                .response
+                .iter()
+                .map(|lineup| {
+                    let side = if lineup.team.id == teams.home.id {
+                        "Home"
+                    } else if lineup.team.id == teams.away.id {
+                        "Away"
+                    } else {
+                        "Team"
+                    };
+                    (side, lineup)
+                })
+                .collect();
+            lineups.sort_by_key(|(side, _)| *side != "Home");
+
+            for (idx, (side, lineup)) in lineups.into_iter().enumerate() {
+                if idx > 0 {
+                    output.push('\n');
+                }
+                Self::write_lineup(&mut output, side, lineup);
+            }
+        } else if let FootballLineupsErrors::WithMessages(error_messages) = &self.errors {
+            let mut buffer = String::with_capacity(500);
+
+            let print_error = |output: &mut String, field_name: &str, error: &str| {
+                let _ = writeln!(output, "Error: {field_name} - {error}");
+            };
+
+            for field_name in &["access", "token", "requests", "fixture"] {
+                if let Some(error) = error_messages.get(*field_name) {
+                    print_error(&mut buffer, field_name, error);
+                }
+            }
+
+            if !buffer.is_empty() {
+                output.push_str(&buffer);
+            }
+        } else {
+            output.push_str("Lineups are not available yet\n");
+        }
+
+        output
+    }
+
+    fn write_lineup(output: &mut StringType, side: &str, lineup: &Lineup) {
+        let _ = write!(output, "{side}: {}", &lineup.team.name);
+        if let Some(formation) = &lineup.formation {
+            let _ = write!(output, " ({formation})");
+        }
+        output.push('\n');
+
+        if let Some(coach) = &lineup.coach.name {
+            let _ = writeln!(output, "Coach: {coach}");
+        }
+
+        let rows: Vec<String> = lineup
+            .formation_rows()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(Player::label)
+                    .collect::<Vec<_>>()
+                    .join("   ")
+            })
+            .collect();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for row in rows {
+            let _ = writeln!(output, "{}", format!("{row:^width$}").trim_end());
+        }
+
+        if !lineup.substitutes.is_empty() {
+            let substitutes: Vec<StringType> = lineup
+                .substitutes
+                .iter()
+                .map(|p| p.player.label())
+                .collect();
+            let _ = writeln!(output, "Substitutes: {}", substitutes.join(", "));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{Away, Home, Teams},
+        football_lineups_data::{FootballLineupsData, FootballLineupsErrors, Paging, Parameters},
+        Error,
+    };
+    use log::info;
+
+    #[test]
+    fn test_football_lineups_data() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/lineups.json");
+        let data: FootballLineupsData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::Fixture("239625".into()));
+
+        let teams = Teams {
+            home: Home {
+                id: 529,
+                name: "Barcelona".into(),
+                ..Home::default()
+            },
+            away: Away {
+                id: 33,
+                name: "Arsenal".into(),
+                ..Away::default()
+            },
+        };
+
+        let buf = data.get_lineups_information(&teams);
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(lines[0], "Home: Barcelona (4-3-3)");
+        assert_eq!(lines[1], "Coach: Xavi");
+        assert_eq!(lines[2].trim(), "1 M. ter Stegen");
+        assert!(lines[2].starts_with("    "));
+        assert_eq!(
+            lines[3],
+            "23 J. Koundé   15 A. Christensen   4 R. Araujo   28 A. Balde"
+        );
+        assert_eq!(lines[4].trim(), "8 Pedri   21 F. de Jong   6 Gavi");
+        assert_eq!(
+            lines[5].trim(),
+            "11 Raphinha   9 R. Lewandowski   27 L. Yamal"
+        );
+        assert_eq!(lines[6], "Substitutes: 13 I. Peña, 7 F. Torres");
+        assert_eq!(lines[7], "");
+        assert_eq!(lines[8], "Away: Arsenal (4-2-3-1)");
+
+        // no grid, split by formation
+        let away = data.get_lineup(33).map(|l| l.formation_rows());
+        let sizes: Vec<usize> = away.unwrap_or_default().iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![1, 4, 2, 3, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_football_data() -> Result<(), Error> {
+        let default_data = FootballLineupsData::default();
+
+        assert_eq!(
+            default_data.get,
+            "".to_string(),
+            "Expected default get value"
+        );
+
+        assert_eq!(
+            default_data.parameters,
+            Parameters::Fixture("".into()),
+            "Expected default parameters"
+        );
+
+        if let FootballLineupsErrors::Empty(empty_errors) = &default_data.errors {
+            assert!(
+                empty_errors.is_empty(),
+                "Expected no errors in default data"
+            );
+        } else {
+            panic!("Unexpected non-empty errors variant in default data");
+        }
+
+        assert_eq!(default_data.results, 0, "Expected default results value");
+
+        assert_eq!(
+            default_data.paging,
+            Paging::default(),
+            "Expected default paging"
+        );
+
+        assert!(
+            default_data.response.is_empty(),
+            "Expected no response data in default"
+        );
+
+        assert_eq!(
+            default_data.get_lineups_information(&Teams::default()),
+            "Lineups are not available yet\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use zbus::{proxy, zvariant::Value, Connection};
+
+use crate::{football_changes::FixtureChange, Error};
+
+/// Name notifications are sent under
+const APP_NAME: &str = "footballscore";
+
+/// Let the notification server decide how long a notification is shown
+const DEFAULT_EXPIRE_TIMEOUT: i32 = -1;
+
+/// `urgency` hint of the notifications, `1` is normal
+const URGENCY_NORMAL: u8 = 1;
+
+#[proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    /// `Notify` method of the Desktop Notifications Specification
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, &Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+}
+
+/// Sends a freedesktop notification over D-Bus for each change of a fixture.
+///
+/// A new notification of a fixture replaces the previous one, so a match
+/// keeps a single notification up to date.
+pub struct Notifier {
+    proxy: NotificationsProxy<'static>,
+    notification_ids: HashMap<u32, u32>,
+}
+
+impl Notifier {
+    /// Notifier on the session bus of the user
+    /// # Errors
+    ///
+    /// Will return error if the session bus cannot be reached
+    pub async fn session() -> Result<Self, Error> {
+        Self::new(&Connection::session().await?).await
+    }
+
+    /// Notifier on the given bus connection
+    /// # Errors
+    ///
+    /// Will return error if the proxy cannot be created
+    pub async fn new(connection: &Connection) -> Result<Self, Error> {
+        Ok(Self {
+            proxy: NotificationsProxy::new(connection).await?,
+            notification_ids: HashMap::new(),
+        })
+    }
+
+    /// Send a notification for `change`, returns the id given by the server
+    /// # Errors
+    ///
+    /// Will return error if the notification server does not answer
+    pub async fn notify(&mut self, change: &FixtureChange) -> Result<u32, Error> {
+        let replaces_id = self
+            .notification_ids
+            .get(&change.fixture_id)
+            .copied()
+            .unwrap_or_default();
+        let urgency = Value::from(URGENCY_NORMAL);
+        let hints = HashMap::from([("urgency", &urgency)]);
+
+        let id = self
+            .proxy
+            .notify(
+                APP_NAME,
+                replaces_id,
+                "",
+                &change.summary(),
+                &change.score(),
+                &[],
+                hints,
+                DEFAULT_EXPIRE_TIMEOUT,
+            )
+            .await?;
+        self.notification_ids.insert(change.fixture_id, id);
+
+        Ok(id)
+    }
+
+    /// Send a notification for every change, nothing is sent without changes
+    /// # Errors
+    ///
+    /// Will return error if the notification server does not answer
+    pub async fn notify_changes(&mut self, changes: &[FixtureChange]) -> Result<(), Error> {
+        for change in changes {
+            self.notify(change).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use parking_lot::Mutex;
+    use std::{collections::HashMap, process::Stdio, sync::Arc};
+    use tokio::{
+        io::{AsyncBufReadExt, BufReader},
+        process::Command,
+    };
+    use zbus::{connection::Builder, interface, zvariant::OwnedValue};
+
+    use crate::{
+        football_changes::detect_changes,
+        football_fixtures_data::{FixtureStatus, FootballFixturesData},
+        football_notify::Notifier,
+        Error,
+    };
+
+    /// Summary, body and `replaces_id` of the received notifications
+    type Received = Arc<Mutex<Vec<(String, String, u32)>>>;
+
+    struct FakeNotificationServer {
+        received: Received,
+    }
+
+    #[interface(name = "org.freedesktop.Notifications")]
+    impl FakeNotificationServer {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            _app_name: String,
+            replaces_id: u32,
+            _app_icon: String,
+            summary: String,
+            body: String,
+            _actions: Vec<String>,
+            _hints: HashMap<String, OwnedValue>,
+            _expire_timeout: i32,
+        ) -> u32 {
+            let mut received = self.received.lock();
+            received.push((summary, body, replaces_id));
+            if replaces_id == 0 {
+                u32::try_from(received.len()).unwrap()
+            } else {
+                replaces_id
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notifier() -> Result<(), Error> {
+        // a private session bus, skipped where no dbus-daemon is installed
+        let Ok(mut daemon) = Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+        else {
+            return Ok(());
+        };
+        let stdout = daemon.stdout.take().unwrap();
+        let mut address = String::new();
+        BufReader::new(stdout).read_line(&mut address).await?;
+
+        let received = Received::default();
+        let _server = Builder::address(address.trim())?
+            .name("org.freedesktop.Notifications")?
+            .serve_at(
+                "/org/freedesktop/Notifications",
+                FakeNotificationServer {
+                    received: received.clone(),
+                },
+            )?
+            .build()
+            .await?;
+
+        let connection = Builder::address(address.trim())?.build().await?;
+        let mut notifier = Notifier::new(&connection).await?;
+
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let previous: FootballFixturesData = serde_json::from_str(buf)?;
+        notifier
+            .notify_changes(&detect_changes(&previous, &previous))
+            .await?;
+        assert!(received.lock().is_empty());
+
+        let mut current = previous.clone();
+        current.response[0].goals.home = Some(1);
+        notifier
+            .notify_changes(&detect_changes(&previous, &current))
+            .await?;
+
+        let previous = current.clone();
+        current.response[0].fixture.status.short = FixtureStatus::FullTime;
+        notifier
+            .notify_changes(&detect_changes(&previous, &current))
+            .await?;
+
+        assert_eq!(
+            *received.lock(),
+            [
+                (
+                    "Goal for Barcelona".into(),
+                    "Barcelona 1 - 1 Arsenal".into(),
+                    0
+                ),
+                ("Full-time".into(), "Barcelona 1 - 1 Arsenal".into(), 1),
+            ]
+        );
+
+        Ok(())
+    }
+}
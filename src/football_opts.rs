@@ -1,23 +1,62 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{football_api::ClubInfo, format_string, Error};
+use chrono::{NaiveDate, Utc};
+
+use crate::{
+    football_api::{ClubInfo, FixtureFilters},
+    football_fixtures_data::{FootballFixturesData, Response, Timezone},
+    football_ics::get_calendar,
+    football_output::{FixturesOutput, OutputFormat, StandingsOutput, TeamsOutput},
+    football_state::FixtureStore,
+    football_statusbar::{StatusBarFormat, StatusBarOutput},
+    football_table::Table,
+    football_template::FixtureTemplate,
+    football_watch::{FixtureWatch, WatchState, DEFAULT_WATCH_INTERVAL},
+    format_string, Error,
+};
+
+#[cfg(feature = "cli")]
+use clap::{ArgGroup, CommandFactory, Parser, Subcommand};
 
 #[cfg(feature = "cli")]
-use clap::{CommandFactory, Parser};
+use tokio::{
+    io::{stdout, AsyncWriteExt},
+    time::sleep,
+};
 
 #[cfg(feature = "cli")]
-use tokio::io::{stdout, AsyncWriteExt};
+use std::{io::IsTerminal, time::Duration};
+
+#[cfg(feature = "notify")]
+use crate::football_notify::Notifier;
+
+#[cfg(feature = "server")]
+use std::net::SocketAddr;
+
+#[cfg(feature = "server")]
+use crate::football_server::{serve, ServerState, DEFAULT_CACHE_TTL};
 
 use crate::{config::Config, ApiStringType, StringType};
 
 #[cfg(feature = "cli")]
-use crate::football_api::FootballApi;
+use crate::{
+    football_api::FootballApi,
+    football_cache::ApiCache,
+    football_webhook::{WebhookSink, DEAD_LETTER_FILE},
+};
+
+#[cfg(feature = "cli")]
+use reqwest::Url;
+
+/// Number of meetings shown by `--h2h` unless `--last-match` is given
+pub const DEFAULT_HEAD_TO_HEAD_MEETINGS: u8 = 5;
 
 /// Utility to retrieve and format football data from api-football.com
 ///
 /// Please specify the `club_id` or use `club_name` to get its ID
 #[cfg(feature = "cli")]
 #[derive(Parser, Default, Serialize, Deserialize)]
+#[clap(group(ArgGroup::new("follow").args(["watch", "since_last_run"])))]
 pub struct FootballOpts {
     /// Api key (optional but either this or `API_KEY` environment variable must exist)
     #[clap(short = 'k', long)]
@@ -27,6 +66,14 @@ pub struct FootballOpts {
     #[clap(long)]
     next_match: Option<u8>,
 
+    /// Last N finished matches, cannot be used with `--next-match` (optional)
+    #[clap(long, conflicts_with = "next_match")]
+    last_match: Option<u8>,
+
+    /// Show at most N of the matches returned by the api (optional)
+    #[clap(long)]
+    limit: Option<usize>,
+
     /// Club id (optional)
     #[clap(short = 'c', long)]
     club_id: Option<u16>,
@@ -34,6 +81,139 @@ pub struct FootballOpts {
     /// Club name (optional)
     #[clap(short = 'n', long)]
     club_name: Option<StringType>,
+
+    /// Show the league table, requires `--league` and `--season` (optional)
+    #[clap(long)]
+    standings: bool,
+
+    /// League id, also narrows the matches down to this league. Without
+    /// `--club-id` the matches of the whole league are shown (optional)
+    #[clap(long)]
+    league: Option<u16>,
+
+    /// Season, the year the season starts in e.g. `2023` (optional)
+    #[clap(long)]
+    season: Option<u16>,
+
+    /// Matches on a single day as `YYYY-MM-DD` (optional)
+    #[clap(long, conflicts_with_all = ["from", "to", "next_match", "last_match"])]
+    date: Option<StringType>,
+
+    /// First day of a date range as `YYYY-MM-DD`, requires `--to` (optional)
+    #[clap(long, requires = "to", conflicts_with_all = ["next_match", "last_match"])]
+    from: Option<StringType>,
+
+    /// Last day of a date range as `YYYY-MM-DD`, requires `--from` (optional)
+    #[clap(long, requires = "from", conflicts_with_all = ["next_match", "last_match"])]
+    to: Option<StringType>,
+
+    /// Append the goals, cards and substitutions timeline of the match (optional)
+    #[clap(long)]
+    events: bool,
+
+    /// Append the starting XI, substitutes and coach of both sides (optional)
+    #[clap(long)]
+    lineups: bool,
+
+    /// Append the home vs away statistics (possession, shots, xG) of the match (optional)
+    #[clap(long)]
+    stats: bool,
+
+    /// Head to head history between two clubs, each given by id or name (optional)
+    #[clap(long, num_args = 2, value_names = ["CLUB", "CLUB"])]
+    h2h: Option<Vec<StringType>>,
+
+    /// IANA name of the zone kick-off times are shown in e.g. `Europe/Madrid`,
+    /// the system local zone is used by default (optional)
+    #[clap(long)]
+    timezone: Option<StringType>,
+
+    /// Output format, `json` and `json-pretty` follow the schema of
+    /// `FixturesOutput` and `TeamsOutput`, `ics` writes the matches as an
+    /// iCalendar, `csv` and `markdown` as a table (optional)
+    #[clap(short = 'o', long, value_enum, default_value_t)]
+    output: OutputFormat,
+
+    /// Summarise the match for a status bar, the waybar `class` is `live`,
+    /// `upcoming`, `finished`, `postponed`, `cancelled`, `none` or `error` (optional)
+    #[clap(
+        long,
+        value_enum,
+        conflicts_with_all = ["output", "standings", "h2h", "club_name", "events", "lineups", "stats"]
+    )]
+    format: Option<StatusBarFormat>,
+
+    /// Write out each match with a format string e.g.
+    /// `{kickoff:%H:%M} {home} {home_goals}-{away_goals} {away}` (optional)
+    #[clap(long, conflicts_with_all = ["output", "format"])]
+    template: Option<StringType>,
+
+    /// Keep polling the matches and redraw them until the live ones are
+    /// over, backs off while no match is live (optional)
+    #[clap(long, conflicts_with_all = ["standings", "h2h", "club_name"])]
+    watch: bool,
+
+    /// Seconds between two polls of a live match with `--watch` (optional)
+    #[clap(long, requires = "watch", default_value_t = DEFAULT_WATCH_INTERVAL)]
+    interval: u64,
+
+    /// Only write out what changed in the matches since the previous run:
+    /// kick-off, goals, red cards, half-time and full-time (optional)
+    #[clap(
+        long,
+        conflicts_with_all = ["standings", "h2h", "club_name", "format", "template"]
+    )]
+    since_last_run: bool,
+
+    /// POST each change as JSON to this url, may be repeated, with `--watch`
+    /// or `--since-last-run` (optional)
+    #[clap(long = "webhook", value_name = "URL", requires = "follow")]
+    webhooks: Vec<StringType>,
+
+    /// Always ask the api, neither read nor write the response cache (optional)
+    #[clap(long)]
+    no_cache: bool,
+
+    /// Ask the api even if a cached response is still fresh, then cache the
+    /// new one (optional)
+    #[clap(long, conflicts_with = "no_cache")]
+    refresh: bool,
+
+    /// Send a desktop notification on kick-off, goals, red cards, half-time
+    /// and full-time, with `--watch` or `--since-last-run` (optional)
+    #[cfg(feature = "notify")]
+    #[clap(long, requires = "follow")]
+    notify: bool,
+
+    #[clap(subcommand)]
+    command: Option<FootballCommand>,
+}
+
+#[cfg(feature = "cli")]
+#[derive(Subcommand, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum FootballCommand {
+    /// Show the plan of the api key and the requests used today and this
+    /// minute, asking for it does not count against the quota
+    Quota,
+
+    /// Serve the matches, clubs and league tables as JSON over HTTP, the
+    /// responses are cached so any number of clients share one api quota
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on
+        #[clap(long, default_value = "127.0.0.1:8080")]
+        bind: SocketAddr,
+
+        /// Seconds a response is served from the cache before the api is
+        /// asked again
+        #[clap(long, default_value_t = DEFAULT_CACHE_TTL)]
+        cache_ttl: u64,
+
+        /// Seconds between two polls of the live matches of a club streamed
+        /// from `/fixtures/live/stream`
+        #[clap(long, default_value_t = DEFAULT_WATCH_INTERVAL)]
+        interval: u64,
+    },
 }
 
 #[cfg(feature = "cli")]
@@ -48,6 +228,35 @@ impl FootballOpts {
 
         let mut stdout = stdout();
 
+        match opts.command {
+            Some(FootballCommand::Quota) => {
+                let api = opts.get_api(config)?;
+                let data = api.get_status_data().await?;
+                let output = data.get_quota_information(api.rate_limit().as_ref());
+                stdout.write_all(output.as_bytes()).await?;
+                return Ok(());
+            }
+            #[cfg(feature = "server")]
+            Some(FootballCommand::Serve {
+                bind,
+                cache_ttl,
+                interval,
+            }) => {
+                let api = opts.get_api(config)?;
+                let club_id = opts.club_id.unwrap_or(config.club_id);
+                let ttl = Duration::from_secs(cache_ttl);
+                let state = ServerState::new(api, club_id, opts.get_timezone()?, ttl)
+                    .with_poll_interval(Duration::from_secs(interval.max(1)));
+                eprintln!("Serving on http://{bind}");
+                return serve(bind, state).await;
+            }
+            None => {}
+        }
+
+        if opts.watch {
+            return opts.watch_fixtures(config).await;
+        }
+
         for output in opts.run_opts(config).await? {
             stdout.write_all(output.as_bytes()).await?;
         }
@@ -55,6 +264,105 @@ impl FootballOpts {
         Ok(())
     }
 
+    /// Poll the matches until the live ones are over, redrawing the output
+    /// in place on a terminal and writing it out line by line otherwise
+    async fn watch_fixtures(&self, config: &Config) -> Result<(), Error> {
+        let api = self.get_api(config)?;
+        let timezone = self.get_timezone()?;
+        let club: ClubInfo = self.get_club(config.club_id, "")?;
+        let mut watch = FixtureWatch::new(Duration::from_secs(self.interval.max(1)));
+        let redraw = std::io::stdout().is_terminal();
+
+        let store = FixtureStore::from_config(config).with_scope(&club);
+        let webhook = self.get_webhook(config, &store)?;
+
+        #[cfg(feature = "notify")]
+        let mut notifier = if self.notify {
+            Some(Notifier::session().await?)
+        } else {
+            None
+        };
+        #[cfg(feature = "notify")]
+        let follow_changes = webhook.is_some() || notifier.is_some();
+        #[cfg(not(feature = "notify"))]
+        let follow_changes = webhook.is_some();
+
+        let mut stdout = stdout();
+
+        loop {
+            let data = match api.get_fixture_data(&club).await {
+                Ok(data) => data,
+                // a dropped connection should not end the watch
+                Err(Error::ReqwestError(_)) => {
+                    eprintln!("Network Request Error");
+                    sleep(watch.retry_interval()).await;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            // neither is a quota used up mid-match, nor a rate limit
+            if let Some(errors) = data.get_api_errors() {
+                eprintln!("Api Error: {errors}");
+                sleep(watch.retry_interval()).await;
+                continue;
+            }
+
+            if redraw {
+                // clear the screen and move the cursor home
+                stdout.write_all(b"\x1b[2J\x1b[H").await?;
+            }
+            for output in self.get_fixtures_output(&api, &data, &timezone).await? {
+                stdout.write_all(output.as_bytes()).await?;
+            }
+            stdout.flush().await?;
+
+            if follow_changes {
+                let changes = store.changes_since_last_run(&data)?;
+                #[cfg(feature = "notify")]
+                if let Some(notifier) = notifier.as_mut() {
+                    notifier.notify_changes(&changes).await?;
+                }
+                if let Some(webhook) = &webhook {
+                    webhook.send(&changes).await?;
+                }
+            }
+
+            match watch.update(&data, Utc::now()) {
+                WatchState::Continue(delay) => sleep(delay).await,
+                WatchState::Finished => return Ok(()),
+            }
+        }
+    }
+
+    /// Webhook posting the changes to the `--webhook` urls, failed
+    /// deliveries are kept next to the last seen fixtures
+    fn get_webhook(
+        &self,
+        config: &Config,
+        store: &FixtureStore,
+    ) -> Result<Option<WebhookSink>, Error> {
+        if self.webhooks.is_empty() {
+            return Ok(None);
+        }
+
+        let mut urls = Vec::with_capacity(self.webhooks.len());
+        for webhook in &self.webhooks {
+            let url = Url::parse(webhook).map_err(|e| {
+                Error::InvalidInputError(format_string!(
+                    "\nERROR: invalid webhook url `{webhook}`: {e}\n"
+                ))
+            })?;
+            urls.push(url);
+        }
+
+        let webhook = WebhookSink::new(urls, store.dir().join(DEAD_LETTER_FILE));
+        Ok(Some(match &config.webhook_secret {
+            Some(secret) => webhook.with_secret(secret),
+            None => webhook,
+        }))
+    }
+
     /// # Errors
     /// Return Error if api key cannot be found
     #[cfg(feature = "cli")]
@@ -64,32 +372,85 @@ impl FootballOpts {
             .as_deref()
             .ok_or_else(|| Error::InvalidInputError(format_string!("invalid api key")))?;
 
-        Ok(FootballApi::new(api_key, &config.api_endpoint))
+        let mut api =
+            FootballApi::builder(api_key, &config.api_endpoint).quota_floor(config.quota_floor);
+        if !self.no_cache {
+            api = api.cache(ApiCache::from_config(config).with_refresh(self.refresh));
+        }
+        api.build()
     }
 
     /// Extract options from `FootballOpts` and apply to `FootballApi`
     /// # Errors
     /// Returns Error if clap help output fails
     pub fn get_club(&self, default_club_id: u16, club_name: &str) -> Result<ClubInfo, Error> {
-        let club = if let Some(club_id) = self.club_id {
-            if let Some(next_match) = self.next_match {
+        // a league without a club shows the matches of the whole league
+        let club_id = match (self.club_id, self.league) {
+            (Some(club_id), _) => club_id,
+            (None, Some(_)) => 0,
+            (None, None) => default_club_id,
+        };
+
+        let club = match (self.next_match, self.last_match) {
+            (Some(next_match), None) => {
                 ClubInfo::from_parameter(club_id, next_match, "".into(), club_name.into())
-            } else {
-                ClubInfo::from_parameter(club_id, 0, "all".into(), club_name.into())
             }
-        } else if self.club_id.is_none() {
-            if let Some(next_match) = self.next_match {
-                ClubInfo::from_parameter(default_club_id, next_match, "".into(), club_name.into())
-            } else {
-                ClubInfo::from_parameter(default_club_id, 0, "all".into(), club_name.into())
+            (None, Some(last_match)) => {
+                ClubInfo::from_parameter(club_id, 0, "".into(), club_name.into())
+                    .with_last(last_match)
+            }
+            (None, None) => ClubInfo::from_parameter(club_id, 0, "all".into(), club_name.into()),
+            (Some(_), Some(_)) => {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--next-match` and `--last-match` cannot be used together\n"
+                )));
             }
-        } else {
-            return Err(Error::InvalidInputError(format_string!(
-                "\nERROR: You must specify the correct value\n"
-            )));
         };
 
-        Ok(club)
+        Ok(club.with_filters(self.get_filters()?))
+    }
+
+    /// League, season and date filters of the fixtures
+    /// # Errors
+    /// Returns Error if a date is not `YYYY-MM-DD`, only one side of the date
+    /// range is given or the range ends before it starts
+    fn get_filters(&self) -> Result<FixtureFilters, Error> {
+        let parse_date = |option: &str, value: &Option<StringType>| {
+            value
+                .as_ref()
+                .map(|date| {
+                    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                        Error::InvalidInputError(format_string!(
+                            "\nERROR: `--{option}` expects a date as YYYY-MM-DD, got `{date}`\n"
+                        ))
+                    })
+                })
+                .transpose()
+        };
+
+        parse_date("date", &self.date)?;
+
+        match (parse_date("from", &self.from)?, parse_date("to", &self.to)?) {
+            (Some(from), Some(to)) if from > to => {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--from` must not be after `--to`\n"
+                )));
+            }
+            (Some(_), None) | (None, Some(_)) => {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--from` and `--to` must be used together\n"
+                )));
+            }
+            _ => {}
+        }
+
+        Ok(FixtureFilters {
+            league: self.league,
+            season: self.season,
+            date: self.date.clone(),
+            from: self.from.clone(),
+            to: self.to.clone(),
+        })
     }
 
     /// # Errors
@@ -97,11 +458,79 @@ impl FootballOpts {
     /// Returns error if call to retreive football data fails
     async fn run_opts(&self, config: &Config) -> Result<Vec<StringType>, Error> {
         let api = self.get_api(config)?;
+        let timezone = self.get_timezone()?;
+
+        if self.standings {
+            let (Some(league), Some(season)) = (self.league, self.season) else {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--standings` requires both `--league` and `--season`\n"
+                )));
+            };
+            if self.output != OutputFormat::Text && !self.output.is_json() {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--standings` is only available as text or json output\n"
+                )));
+            }
+            let data = api.get_standings_data(league, season).await?;
+
+            if self.output.is_json() {
+                return Ok(vec![self.output.to_json(&StandingsOutput::from(&data))?]);
+            }
+
+            let output = vec![data.get_standings_table(self.club_id.unwrap_or(config.club_id))];
+            return Ok(output);
+        }
+
+        if let Some(clubs) = &self.h2h {
+            let mut club_ids = Vec::with_capacity(clubs.len());
+            for club in clubs {
+                club_ids.push(Self::resolve_club_id(&api, club).await?);
+            }
+            let [first_id, second_id] = club_ids[..] else {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--h2h` requires exactly two clubs\n"
+                )));
+            };
+            let data = api
+                .get_head_to_head_data(
+                    first_id,
+                    second_id,
+                    self.last_match.unwrap_or(DEFAULT_HEAD_TO_HEAD_MEETINGS),
+                )
+                .await?;
+
+            if self.output.is_json() {
+                let fixtures = FixturesOutput::from_fixtures(&data, None, &timezone)?;
+                return Ok(vec![self.output.to_json(&fixtures)?]);
+            }
+            if self.output == OutputFormat::Ics {
+                return Ok(vec![get_calendar(&data, &Utc::now())?]);
+            }
+            if self.output.is_table() {
+                let table = Table::from_fixtures(&data, None, &timezone)?;
+                return Ok(vec![self.output.to_table(&table)]);
+            }
+
+            let output = vec![data.get_head_to_head(first_id, second_id, &timezone)?];
+            return Ok(output);
+        }
 
         if let Some(name) = &self.club_name {
             let club = self.get_club(config.club_id, name)?;
+            if self.output == OutputFormat::Ics {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: `--club-name` is not available as ics output\n"
+                )));
+            }
             let data = api.get_team_data(&club).await?;
 
+            if self.output.is_json() {
+                return Ok(vec![self.output.to_json(&TeamsOutput::from(&data))?]);
+            }
+            if self.output.is_table() {
+                return Ok(vec![self.output.to_table(&Table::from_teams(&data)?)]);
+            }
+
             let output = vec![data.get_teams_information()];
             return Ok(output);
         }
@@ -109,16 +538,212 @@ impl FootballOpts {
         let club: ClubInfo = self.get_club(config.club_id, "")?;
         let data = api.get_fixture_data(&club).await?;
 
-        let output = vec![data.get_current_fixtures()];
+        if self.since_last_run {
+            let store = FixtureStore::from_config(config).with_scope(&club);
+            let changes = store.changes_since_last_run(&data)?;
+
+            #[cfg(feature = "notify")]
+            if self.notify && !changes.is_empty() {
+                Notifier::session().await?.notify_changes(&changes).await?;
+            }
+            if let Some(webhook) = self.get_webhook(config, &store)? {
+                webhook.send(&changes).await?;
+            }
+
+            if self.output.is_json() {
+                return Ok(vec![self.output.to_json(&changes)?]);
+            }
+            return Ok(changes
+                .iter()
+                .map(|change| format_string!("{change}\n"))
+                .collect());
+        }
+
+        self.get_fixtures_output(&api, &data, &timezone).await
+    }
+
+    /// Write out the matches in the requested output format
+    async fn get_fixtures_output(
+        &self,
+        api: &FootballApi,
+        data: &FootballFixturesData,
+        timezone: &Timezone,
+    ) -> Result<Vec<StringType>, Error> {
+        if let Some(format) = self.format {
+            let status = StatusBarOutput::from_fixtures(data, timezone)?;
+            return Ok(vec![status.render(format)?]);
+        }
+
+        if self.output == OutputFormat::Ics {
+            return Ok(vec![get_calendar(data, &Utc::now())?]);
+        }
+
+        if self.output.is_table() {
+            let table = Table::from_fixtures(data, self.limit, timezone)?;
+            return Ok(vec![self.output.to_table(&table)]);
+        }
+
+        let mut output = Vec::new();
+        let mut json = None;
+
+        if self.output.is_json() {
+            json = Some(FixturesOutput::from_fixtures(data, self.limit, timezone)?);
+        } else if let Some(template) = &self.template {
+            let template: FixtureTemplate = template.parse()?;
+            output.push(template.render_fixtures(data, self.limit, timezone)?);
+        } else if self.last_match.is_some() {
+            output.push(data.get_results(timezone)?);
+        } else {
+            output.push(data.get_fixtures_information(self.limit, timezone)?);
+        }
+
+        if let Some(response) = self.get_detailed_fixture(data, json.as_ref()) {
+            let fixture_id = response.fixture.id;
+            let mut fixture = json
+                .as_mut()
+                .and_then(|json| json.get_fixture_mut(fixture_id));
+
+            if self.events {
+                let events = api.get_events_data(fixture_id).await?;
+                match fixture.as_mut() {
+                    Some(fixture) => fixture.events = Some(events.response),
+                    None => output.push(events.get_events_timeline()),
+                }
+            }
+
+            if self.lineups {
+                match fixture.as_mut() {
+                    Some(fixture) => {
+                        // passed through as is, the api has more than we model
+                        let lineups = api.get_lineups_json(fixture_id).await?;
+                        fixture.lineups = Some(Self::get_lineups_response(fixture_id, &lineups)?);
+                    }
+                    None => {
+                        let lineups = api.get_lineups_data(fixture_id).await?;
+                        output.push(lineups.get_lineups_information(&response.teams));
+                    }
+                }
+            }
+
+            if self.stats {
+                let statistics = api.get_statistics_data(fixture_id).await?;
+                match fixture.as_mut() {
+                    Some(fixture) => fixture.statistics = Some(statistics.response),
+                    None => output.push(statistics.get_statistics_comparison(&response.teams)),
+                }
+            }
+        }
+
+        if let Some(json) = json {
+            return Ok(vec![self.output.to_json(&json)?]);
+        }
+
         Ok(output)
     }
 
+    /// The fixture `--events`, `--lineups` and `--stats` are fetched for,
+    /// i.e. the first one written out
+    fn get_detailed_fixture<'a>(
+        &self,
+        data: &'a FootballFixturesData,
+        json: Option<&FixturesOutput>,
+    ) -> Option<&'a Response> {
+        match json {
+            Some(json) => json.fixtures.first().and_then(|fixture| {
+                data.response
+                    .iter()
+                    .find(|response| response.fixture.id == fixture.id)
+            }),
+            // results are written out most recent first
+            None if self.last_match.is_some() => data.get_latest_fixture(),
+            None => data.get_earliest_fixture(),
+        }
+    }
+
+    /// `response` of the lineups body the api sent for `fixture_id`
+    /// # Errors
+    ///
+    /// Will return error if the body is not JSON, holds the errors the api
+    /// answered with or has no `response`
+    fn get_lineups_response(fixture_id: u32, body: &str) -> Result<serde_json::Value, Error> {
+        let mut body: serde_json::Value = serde_json::from_str(body)?;
+
+        let has_errors = match body.get("errors") {
+            Some(serde_json::Value::Object(errors)) => !errors.is_empty(),
+            Some(serde_json::Value::Array(errors)) => !errors.is_empty(),
+            _ => false,
+        };
+        if has_errors {
+            return Err(Error::InvalidValue(format_string!(
+                "lineups of fixture {fixture_id} failed: {}",
+                body["errors"]
+            )));
+        }
+
+        body.get_mut("response")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| {
+                Error::InvalidValue(format_string!(
+                    "lineups of fixture {fixture_id} have no `response`"
+                ))
+            })
+    }
+
+    /// Use `club` as is when it is an id, otherwise look up the id of the
+    /// first club matching the name
+    async fn resolve_club_id(api: &FootballApi, club: &str) -> Result<u16, Error> {
+        if let Ok(club_id) = club.parse() {
+            return Ok(club_id);
+        }
+
+        let club_info = ClubInfo::from_parameter(0, 0, "".into(), club.into());
+        let data = api.get_team_data(&club_info).await?;
+
+        data.response
+            .first()
+            .and_then(|response| response.team.id)
+            .ok_or_else(|| {
+                Error::InvalidInputError(format_string!(
+                    "\nERROR: unable to find a club named `{club}`\n"
+                ))
+            })
+    }
+
+    /// # Errors
+    /// Returns Error if the timezone is not a known IANA name
+    fn get_timezone(&self) -> Result<Timezone, Error> {
+        self.timezone
+            .as_ref()
+            .map_or(Ok(Timezone::default()), |timezone| timezone.parse())
+    }
+
     fn apply_defaults(&mut self, config: &Config) {
         if self.api_key.is_none() {
             self.api_key.clone_from(&config.api_key);
         }
 
-        if self.club_id.is_none() {
+        if self.timezone.is_none() {
+            self.timezone.clone_from(&config.timezone);
+        }
+
+        if self.webhooks.is_empty() {
+            if let Some(webhook_urls) = &config.webhook_urls {
+                self.webhooks = webhook_urls
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|url| !url.is_empty())
+                    .map(Into::into)
+                    .collect();
+            }
+        }
+
+        // the configured template only applies to the text output
+        if self.template.is_none() && self.format.is_none() && self.output == OutputFormat::Text {
+            self.template.clone_from(&config.template);
+        }
+
+        // keep the club unset so `--league` alone shows the whole league
+        if self.club_id.is_none() && self.league.is_none() {
             self.club_id = Some(config.club_id);
         }
     }
@@ -137,7 +762,9 @@ mod tests {
 
     use crate::{
         config::{Config, TestEnvs},
-        football_api::ClubInfo,
+        football_api::{ClubInfo, FixtureFilters},
+        football_fixtures_data::{FootballFixturesData, Timezone},
+        football_output::{FixturesOutput, OutputFormat},
         Error,
     };
 
@@ -175,16 +802,19 @@ mod tests {
         let endpoint_fixtures = opts.get_club(529, "")?;
         let live = "StackString(\"all\")";
         let name = "StackString(\"\")";
+        let filters =
+            "FixtureFilters { league: None, season: None, date: None, from: None, to: None }";
         let expected =
-            format!("EndpointParams {{ team: 529, next: 0, live: {live}, name: {name} }}");
+            format!("EndpointParams {{ team: 529, next: 0, last: 0, live: {live}, name: {name}, filters: {filters} }}");
 
         assert_eq!(format!("{endpoint_fixtures:?}"), expected);
 
         let endpoint_teams = opts.get_club(0, "arsenal")?;
         let live = "StackString(\"all\")";
         let name = "StackString(\"arsenal\")";
-        let expected =
-            format!("EndpointParams {{ team: 529, next: 0, live: {live}, name: {name} }}",);
+        let expected = format!(
+            "EndpointParams {{ team: 529, next: 0, last: 0, live: {live}, name: {name}, filters: {filters} }}",
+        );
 
         assert_eq!(format!("{endpoint_teams:?}"), expected);
         Ok(())
@@ -192,11 +822,13 @@ mod tests {
 
     #[test]
     fn test_apply_defaults() -> Result<(), Error> {
-        let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+        let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID", "TIMEZONE", "TEMPLATE"]);
 
         set_var("API_KEY", "1e5765fc0c22df4e4ccf20581c2ef3d7");
         set_var("API_ENDPOINT", "test.local");
         set_var("CLUB_ID", "529");
+        set_var("TIMEZONE", "Europe/Madrid");
+        set_var("TEMPLATE", "{home} vs {away}");
 
         let config = Config::init_config(None)?;
         drop(_env);
@@ -207,6 +839,23 @@ mod tests {
         assert_eq!(opts.club_id, Some(529));
         assert_eq!(opts.club_name, None);
         assert_eq!(opts.next_match, None);
+        assert_eq!(opts.timezone, Some("Europe/Madrid".into()));
+        assert_eq!(
+            opts.get_timezone()?,
+            Timezone::Named(chrono_tz::Europe::Madrid)
+        );
+
+        opts.timezone = Some("Europe/Barcelona".into());
+        assert!(opts.get_timezone().is_err());
+        assert_eq!(opts.template, Some("{home} vs {away}".into()));
+
+        // the configured template does not apply to the json output
+        let mut opts = FootballOpts {
+            output: OutputFormat::Json,
+            ..Default::default()
+        };
+        opts.apply_defaults(&config);
+        assert_eq!(opts.template, None);
         Ok(())
     }
 
@@ -245,6 +894,79 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "cli")]
+    #[tokio::test]
+    async fn test_run_opts_csv_standings() -> Result<(), Error> {
+        let opts = FootballOpts {
+            api_key: Some("1e5765fc0c22df4e4ccf20581c2ef3d7".into()),
+            standings: true,
+            league: Some(140),
+            season: Some(2023),
+            output: OutputFormat::Csv,
+            ..Default::default()
+        };
+
+        let result = opts.run_opts(&Config::default()).await;
+        assert!(matches!(result, Err(Error::InvalidInputError(_))));
+        Ok(())
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_get_detailed_fixture() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+        let timezone = Timezone::default();
+        let detailed = |opts: &FootballOpts, json: Option<&FixturesOutput>| {
+            opts.get_detailed_fixture(&data, json)
+                .map(|response| response.fixture.timestamp)
+        };
+
+        // the api does not return the fixtures in kick-off order
+        assert_eq!(data.response[0].fixture.timestamp, 1691548200);
+
+        let opts = FootballOpts::default();
+        assert_eq!(detailed(&opts, None), Some(1270061100));
+
+        let opts = FootballOpts {
+            last_match: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(detailed(&opts, None), Some(1691548200));
+
+        // the json output keeps the earliest fixtures, whatever the mode
+        let json = FixturesOutput::from_fixtures(&data, Some(1), &timezone)?;
+        assert_eq!(detailed(&opts, Some(&json)), Some(1270061100));
+
+        assert_eq!(
+            detailed(&opts, Some(&FixturesOutput::default())),
+            None,
+            "nothing written out, nothing fetched"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_get_lineups_response() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/lineups.json");
+        let lineups = FootballOpts::get_lineups_response(1, buf)?;
+        assert_eq!(lineups[1]["startXI"][0]["player"]["name"], "M. ter Stegen");
+
+        let buf = r#"{"errors": {"requests": "request limit reached"}, "response": []}"#;
+        let error = FootballOpts::get_lineups_response(1, buf).map(|_| ());
+        assert!(
+            matches!(&error, Err(Error::InvalidValue(message)) if message.contains("request limit"))
+        );
+
+        for buf in ["[]", "\"lineups\"", "{}"] {
+            assert!(FootballOpts::get_lineups_response(1, buf).is_err());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_fixtures() -> Result<(), Error> {
         // next fixture
@@ -260,8 +982,10 @@ mod tests {
             ClubInfo::EndpointParams {
                 team: 529,
                 next: 1,
+                last: 0,
                 live: "".into(),
-                name: "".into()
+                name: "".into(),
+                filters: FixtureFilters::default(),
             }
         );
 
@@ -277,11 +1001,103 @@ mod tests {
             ClubInfo::EndpointParams {
                 team: 529,
                 next: 0,
+                last: 0,
                 live: "all".into(),
-                name: "".into()
+                name: "".into(),
+                filters: FixtureFilters::default(),
+            }
+        );
+
+        // last results
+        let opts = FootballOpts {
+            club_id: Some(529),
+            last_match: Some(3),
+            ..Default::default()
+        };
+        let club = opts.get_club(opts.club_id.unwrap_or_default(), "")?;
+
+        assert_eq!(
+            club,
+            ClubInfo::EndpointParams {
+                team: 529,
+                next: 0,
+                last: 3,
+                live: "".into(),
+                name: "".into(),
+                filters: FixtureFilters::default(),
+            }
+        );
+
+        let opts = FootballOpts {
+            next_match: Some(1),
+            last_match: Some(3),
+            ..Default::default()
+        };
+        assert!(opts.get_club(529, "").is_err());
+
+        // league card of a single day
+        let opts = FootballOpts {
+            league: Some(140),
+            season: Some(2024),
+            date: Some("2024-10-19".into()),
+            ..Default::default()
+        };
+        let club = opts.get_club(529, "")?;
+
+        assert_eq!(
+            club,
+            ClubInfo::EndpointParams {
+                team: 0,
+                next: 0,
+                last: 0,
+                live: "".into(),
+                name: "".into(),
+                filters: FixtureFilters {
+                    league: Some(140),
+                    season: Some(2024),
+                    date: Some("2024-10-19".into()),
+                    ..FixtureFilters::default()
+                },
             }
         );
 
+        // date range of the club
+        let opts = FootballOpts {
+            club_id: Some(529),
+            from: Some("2024-10-19".into()),
+            to: Some("2024-10-27".into()),
+            ..Default::default()
+        };
+        let club = opts.get_club(529, "")?;
+
+        assert_eq!(
+            club.get_param_options(),
+            vec![
+                ("team", "529".into()),
+                ("from", "2024-10-19".into()),
+                ("to", "2024-10-27".into()),
+            ]
+        );
+
+        let opts = FootballOpts {
+            from: Some("2024-10-27".into()),
+            to: Some("2024-10-19".into()),
+            ..Default::default()
+        };
+        assert!(opts.get_club(529, "").is_err());
+
+        let opts = FootballOpts {
+            date: Some("19/10/2024".into()),
+            ..Default::default()
+        };
+        assert!(opts.get_club(529, "").is_err());
+
+        let opts = FootballOpts {
+            from: Some("2024-10-19".into()),
+            ..Default::default()
+        };
+        assert!(opts.get_club(529, "").is_err());
+
         // club information
         let opts = FootballOpts {
             club_id: None,
@@ -299,8 +1115,10 @@ mod tests {
             ClubInfo::EndpointParams {
                 team: 0,
                 next: 0,
+                last: 0,
                 live: "all".into(),
-                name: "arsenal".into()
+                name: "arsenal".into(),
+                filters: FixtureFilters::default(),
             }
         );
 
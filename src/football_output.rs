@@ -0,0 +1,487 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+use crate::{
+    football_events_data::Event,
+    football_fixtures_data::{FixtureStatus, FootballErrors, FootballFixturesData, Timezone},
+    football_standings_data::{FootballStandingsData, FootballStandingsErrors, Standing},
+    football_statistics_data::TeamStatistics,
+    football_table::Table,
+    football_teams_data::{FootballTeamsData, FootballTeamsErrors, Venue},
+    Error, StringType,
+};
+
+/// How the data is written out
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One line of JSON
+    Json,
+    /// Indented JSON
+    JsonPretty,
+    /// iCalendar with one event per match
+    Ics,
+    /// Comma separated values with a header line
+    Csv,
+    /// Markdown table
+    Markdown,
+}
+
+impl OutputFormat {
+    #[must_use]
+    pub fn is_json(self) -> bool {
+        matches!(self, Self::Json | Self::JsonPretty)
+    }
+
+    #[must_use]
+    pub fn is_table(self) -> bool {
+        matches!(self, Self::Csv | Self::Markdown)
+    }
+
+    /// Serialize `value` as JSON followed by a newline
+    /// # Errors
+    ///
+    /// Will return error if serialization fails
+    pub fn to_json<T: Serialize>(self, value: &T) -> Result<StringType, Error> {
+        let mut output: StringType = match self {
+            Self::JsonPretty => serde_json::to_string_pretty(value)?.into(),
+            Self::Text | Self::Json | Self::Ics | Self::Csv | Self::Markdown => {
+                serde_json::to_string(value)?.into()
+            }
+        };
+        output.push('\n');
+        Ok(output)
+    }
+
+    /// Write out `table` as CSV, or as Markdown for every other format
+    #[must_use]
+    pub fn to_table(self, table: &Table) -> StringType {
+        match self {
+            Self::Csv => table.to_csv(),
+            Self::Text | Self::Json | Self::JsonPretty | Self::Ics | Self::Markdown => {
+                table.to_markdown()
+            }
+        }
+    }
+}
+
+/// Error reported by the api, e.g. `{"field": "token", "message": "..."}`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorOutput {
+    pub field: StringType,
+    pub message: StringType,
+}
+
+impl ErrorOutput {
+    /// Errors ordered by field, the api returns them as an object
+    pub(crate) fn from_messages(error_messages: &HashMap<String, String>) -> Vec<Self> {
+        error_messages
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(field, message)| Self {
+                field: field.as_str().into(),
+                message: message.as_str().into(),
+            })
+            .collect()
+    }
+}
+
+/// One side of a fixture
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SideOutput {
+    pub id: u16,
+    pub name: StringType,
+
+    /// `null` until the fixture kicks off
+    pub goals: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeagueOutput {
+    pub id: u16,
+    pub name: StringType,
+    pub country: StringType,
+    pub season: u16,
+    pub round: StringType,
+}
+
+/// A fixture as written out by `--output json`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FixtureOutput {
+    pub id: u32,
+
+    /// RFC 3339 kick-off in the requested timezone
+    pub kickoff: StringType,
+
+    /// Unix timestamp of the kick-off
+    pub timestamp: u32,
+
+    /// api-football short status code, e.g. `NS`, `2H` or `FT`
+    pub status: FixtureStatus,
+
+    /// Minutes played, `null` before kick-off
+    pub elapsed: Option<u8>,
+
+    pub league: LeagueOutput,
+    pub venue: StringType,
+    pub home: SideOutput,
+    pub away: SideOutput,
+
+    /// Only present with `--events`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub events: Option<Vec<Event>>,
+
+    /// Only present with `--lineups`, the lineups as sent by the api
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lineups: Option<serde_json::Value>,
+
+    /// Only present with `--stats`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub statistics: Option<Vec<TeamStatistics>>,
+}
+
+/// Fixtures as written out by `--output json`, ordered by kick-off.
+///
+/// `errors` is empty unless the api rejected the request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct FixturesOutput {
+    pub fixtures: Vec<FixtureOutput>,
+    pub errors: Vec<ErrorOutput>,
+}
+
+impl FixturesOutput {
+    /// Fixtures ordered by kick-off, keeping at most `limit` of them
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData, football_output::FixturesOutput,
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let output = FixturesOutput::from_fixtures(&data, None, &"UTC".parse()?)?;
+    ///
+    /// assert_eq!(output.fixtures[0].home.name, "Barcelona");
+    /// assert_eq!(output.fixtures[0].kickoff, "2020-02-06T14:00:00+00:00");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn from_fixtures(
+        data: &FootballFixturesData,
+        limit: Option<usize>,
+        timezone: &Timezone,
+    ) -> Result<Self, Error> {
+        let mut responses: Vec<_> = data.response.iter().collect();
+        responses.sort_by_key(|response| response.fixture.timestamp);
+
+        if let Some(limit) = limit {
+            responses.truncate(limit);
+        }
+
+        let mut fixtures = Vec::with_capacity(responses.len());
+
+        for response in responses {
+            fixtures.push(FixtureOutput {
+                id: response.fixture.id,
+                kickoff: response.kickoff(timezone)?.to_rfc3339().into(),
+                timestamp: response.fixture.timestamp,
+                status: response.fixture.status.short.clone(),
+                elapsed: response.fixture.status.elapsed,
+                league: LeagueOutput {
+                    id: response.league.id,
+                    name: response.league.name.clone(),
+                    country: response.league.country.clone(),
+                    season: response.league.season,
+                    round: response.league.round.clone(),
+                },
+                venue: response.fixture.venue.name.clone(),
+                home: SideOutput {
+                    id: response.teams.home.id,
+                    name: response.teams.home.name.clone(),
+                    goals: response.goals.home,
+                },
+                away: SideOutput {
+                    id: response.teams.away.id,
+                    name: response.teams.away.name.clone(),
+                    goals: response.goals.away,
+                },
+                events: None,
+                lineups: None,
+                statistics: None,
+            });
+        }
+
+        let errors = match &data.errors {
+            FootballErrors::WithMessages(error_messages) => {
+                ErrorOutput::from_messages(error_messages)
+            }
+            FootballErrors::Empty(_) => Vec::new(),
+        };
+
+        Ok(Self { fixtures, errors })
+    }
+
+    /// Fixture to attach the events, lineups or statistics to
+    pub fn get_fixture_mut(&mut self, fixture_id: u32) -> Option<&mut FixtureOutput> {
+        self.fixtures
+            .iter_mut()
+            .find(|fixture| fixture.id == fixture_id)
+    }
+}
+
+/// A club as written out by `--output json`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TeamOutput {
+    pub id: Option<u16>,
+    pub name: Option<StringType>,
+    pub code: Option<StringType>,
+    pub country: Option<StringType>,
+    pub founded: Option<u16>,
+    pub national: Option<bool>,
+    pub logo: Option<StringType>,
+    pub venue: Venue,
+}
+
+/// Clubs matching `--club-name` as written out by `--output json`.
+///
+/// `errors` is empty unless the api rejected the request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TeamsOutput {
+    pub teams: Vec<TeamOutput>,
+    pub errors: Vec<ErrorOutput>,
+}
+
+impl From<&FootballTeamsData> for TeamsOutput {
+    fn from(data: &FootballTeamsData) -> Self {
+        let teams = data
+            .response
+            .iter()
+            .map(|response| TeamOutput {
+                id: response.team.id,
+                name: response.team.name.clone(),
+                code: response.team.code.clone(),
+                country: response.team.country.clone(),
+                founded: response.team.founded,
+                national: response.team.national,
+                logo: response.team.logo.clone(),
+                venue: response.venue.clone(),
+            })
+            .collect();
+
+        let errors = match &data.errors {
+            FootballTeamsErrors::WithMessages(error_messages) => {
+                ErrorOutput::from_messages(error_messages)
+            }
+            FootballTeamsErrors::Empty(_) => Vec::new(),
+        };
+
+        Self { teams, errors }
+    }
+}
+
+/// A club in a league table
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClubOutput {
+    pub id: u16,
+    pub name: StringType,
+}
+
+/// A row of a league table
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StandingOutput {
+    pub rank: u16,
+    pub team: ClubOutput,
+    pub played: Option<u16>,
+    pub win: Option<u16>,
+    pub draw: Option<u16>,
+    pub lose: Option<u16>,
+    pub goals_for: Option<u16>,
+    pub goals_against: Option<u16>,
+    pub goals_diff: i16,
+    pub points: i16,
+
+    /// e.g. `DWWWL`, most recent match last
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub form: Option<StringType>,
+
+    /// What the rank qualifies for, e.g. `Promotion - Champions League`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<StringType>,
+}
+
+impl From<&Standing> for StandingOutput {
+    fn from(standing: &Standing) -> Self {
+        Self {
+            rank: standing.rank,
+            team: ClubOutput {
+                id: standing.team.id,
+                name: standing.team.name.clone(),
+            },
+            played: standing.all.played,
+            win: standing.all.win,
+            draw: standing.all.draw,
+            lose: standing.all.lose,
+            goals_for: standing.all.goals.scored,
+            goals_against: standing.all.goals.against,
+            goals_diff: standing.goals_diff,
+            points: standing.points,
+            form: standing.form.clone(),
+            description: standing.description.clone(),
+        }
+    }
+}
+
+/// A league with one table per group, most leagues only have a single group
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeagueStandingsOutput {
+    pub id: u16,
+    pub name: StringType,
+    pub country: StringType,
+    pub season: u16,
+    pub tables: Vec<Vec<StandingOutput>>,
+}
+
+/// League tables as written out by the `/standings` route of `serve`.
+///
+/// `errors` is empty unless the api rejected the request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct StandingsOutput {
+    pub leagues: Vec<LeagueStandingsOutput>,
+    pub errors: Vec<ErrorOutput>,
+}
+
+impl From<&FootballStandingsData> for StandingsOutput {
+    fn from(data: &FootballStandingsData) -> Self {
+        let leagues = data
+            .response
+            .iter()
+            .map(|response| LeagueStandingsOutput {
+                id: response.league.id,
+                name: response.league.name.clone(),
+                country: response.league.country.clone(),
+                season: response.league.season,
+                tables: response
+                    .league
+                    .standings
+                    .iter()
+                    .map(|table| table.iter().map(StandingOutput::from).collect())
+                    .collect(),
+            })
+            .collect();
+
+        let errors = match &data.errors {
+            FootballStandingsErrors::WithMessages(error_messages) => {
+                ErrorOutput::from_messages(error_messages)
+            }
+            FootballStandingsErrors::Empty(_) => Vec::new(),
+        };
+
+        Self { leagues, errors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{FootballFixturesData, Timezone},
+        football_output::{FixturesOutput, OutputFormat, StandingsOutput, TeamsOutput},
+        football_standings_data::FootballStandingsData,
+        football_teams_data::FootballTeamsData,
+        Error,
+    };
+
+    #[test]
+    fn test_fixtures_output() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+        let timezone = Timezone::Named(chrono_tz::Europe::London);
+
+        let output = FixturesOutput::from_fixtures(&data, Some(2), &timezone)?;
+        let json = OutputFormat::Json.to_json(&output)?;
+        assert!(json.ends_with("}\n") && !json.contains("\n "));
+
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["fixtures"].as_array().map(Vec::len), Some(2));
+        assert_eq!(value["fixtures"][0]["kickoff"], "2010-03-31T19:45:00+01:00");
+        assert_eq!(value["fixtures"][0]["status"], "FT");
+        assert_eq!(value["fixtures"][0]["home"]["name"], "Arsenal");
+        assert_eq!(value["fixtures"][0]["home"]["goals"], 2);
+        assert_eq!(value["fixtures"][1]["league"]["round"], "Quarter-finals");
+        assert!(value["fixtures"][0].get("events").is_none());
+        assert_eq!(value["errors"], serde_json::json!([]));
+
+        let pretty = OutputFormat::JsonPretty.to_json(&output)?;
+        assert!(pretty.starts_with("{\n  \"fixtures\": [\n"));
+        let parsed: FixturesOutput = serde_json::from_str(&pretty)?;
+        assert_eq!(parsed, output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_output() -> Result<(), Error> {
+        let buf = r#"{
+            "get": "teams",
+            "parameters": {"name": "arsenal"},
+            "errors": {"token": "Missing application key.", "access": "Suspended"},
+            "results": 0,
+            "paging": {"current": 1, "total": 1},
+            "response": []
+        }"#;
+        let data: FootballTeamsData = serde_json::from_str(buf)?;
+
+        let json = OutputFormat::Json.to_json(&TeamsOutput::from(&data))?;
+        assert_eq!(
+            json,
+            "{\"teams\":[],\"errors\":[{\"field\":\"access\",\"message\":\"Suspended\"},\
+             {\"field\":\"token\",\"message\":\"Missing application key.\"}]}\n"
+        );
+
+        let buf = include_str!("../tests/resource/teams.json");
+        let data: FootballTeamsData = serde_json::from_str(buf)?;
+        let output = TeamsOutput::from(&data);
+        assert_eq!(output.teams[0].name, Some("Barcelona".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_standings_output() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/standings.json");
+        let data: FootballStandingsData = serde_json::from_str(buf)?;
+
+        let json = OutputFormat::Json.to_json(&StandingsOutput::from(&data))?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["leagues"][0]["name"], "La Liga");
+        assert_eq!(value["leagues"][0]["season"], 2023);
+
+        let row = &value["leagues"][0]["tables"][0][1];
+        assert_eq!(row["rank"], 2);
+        assert_eq!(row["team"]["id"], 529);
+        assert_eq!(row["team"]["name"], "Barcelona");
+        assert_eq!(row["points"], 85);
+        assert_eq!(row["goals_diff"], 35);
+        assert_eq!(row["form"], "WWWWL");
+        assert!(row.get("logo").is_none() && row.get("home").is_none());
+        assert_eq!(value["errors"], serde_json::json!([]));
+
+        Ok(())
+    }
+}
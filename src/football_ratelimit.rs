@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{format_string, Error};
+
+#[cfg(feature = "cli")]
+use reqwest::header::HeaderMap;
+
+/// Requests a day, reset at midnight UTC
+pub const REQUESTS_LIMIT_HEADER: &str = "x-ratelimit-requests-limit";
+
+/// Requests left today
+pub const REQUESTS_REMAINING_HEADER: &str = "x-ratelimit-requests-remaining";
+
+/// Requests a minute
+pub const MINUTE_LIMIT_HEADER: &str = "x-ratelimit-limit";
+
+/// Requests left this minute
+pub const MINUTE_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+
+/// The per minute quota starts over a minute after it was used up
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// Request quota reported by the rate limit headers of an api response
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests_limit: Option<u32>,
+    pub requests_remaining: Option<u32>,
+    pub minute_limit: Option<u32>,
+    pub minute_remaining: Option<u32>,
+
+    /// When the response carrying the headers was received
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// Quota of the response with `headers`, `None` without any rate limit
+    /// header
+    #[cfg(feature = "cli")]
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+
+        let rate_limit = Self {
+            requests_limit: header(REQUESTS_LIMIT_HEADER),
+            requests_remaining: header(REQUESTS_REMAINING_HEADER),
+            minute_limit: header(MINUTE_LIMIT_HEADER),
+            minute_remaining: header(MINUTE_REMAINING_HEADER),
+            updated_at: now,
+        };
+
+        let has_headers = rate_limit.requests_limit.is_some()
+            || rate_limit.requests_remaining.is_some()
+            || rate_limit.minute_limit.is_some()
+            || rate_limit.minute_remaining.is_some();
+        has_headers.then_some(rate_limit)
+    }
+
+    /// Requests left today, the daily quota starts over at midnight UTC
+    #[must_use]
+    pub fn remaining_today(&self, now: DateTime<Utc>) -> Option<u32> {
+        if self.updated_at.date_naive() == now.date_naive() {
+            self.requests_remaining
+        } else {
+            self.requests_limit
+        }
+    }
+
+    /// How long to wait before the next request: `None` right away, the
+    /// rest of the minute when the per minute quota is used up
+    /// # Errors
+    ///
+    /// Will return error if no more than `floor` requests are left today
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use footballscore::football_ratelimit::RateLimit;
+    /// # use anyhow::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let updated_at = Utc.with_ymd_and_hms(2024, 10, 19, 18, 0, 0).unwrap();
+    /// let rate_limit = RateLimit {
+    ///     requests_limit: Some(100),
+    ///     requests_remaining: Some(5),
+    ///     minute_limit: Some(10),
+    ///     minute_remaining: Some(3),
+    ///     updated_at,
+    /// };
+    ///
+    /// assert_eq!(rate_limit.check(0, updated_at)?, None);
+    /// assert!(rate_limit.check(5, updated_at).is_err());
+    ///
+    /// // the next day the full quota is back
+    /// let tomorrow = Utc.with_ymd_and_hms(2024, 10, 20, 0, 0, 1).unwrap();
+    /// assert_eq!(rate_limit.check(5, tomorrow)?, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check(&self, floor: u32, now: DateTime<Utc>) -> Result<Option<Duration>, Error> {
+        if let Some(remaining) = self.remaining_today(now) {
+            if remaining <= floor {
+                return Err(Error::QuotaError(format_string!(
+                    "{remaining} requests left today, the floor is {floor}"
+                )));
+            }
+        }
+
+        if self.minute_remaining == Some(0) {
+            let elapsed = (now - self.updated_at).to_std().unwrap_or_default();
+            if elapsed < MINUTE {
+                return Ok(Some(MINUTE - elapsed));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::{football_ratelimit::RateLimit, Error};
+
+    #[cfg(feature = "cli")]
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_rate_limit() -> Result<(), Error> {
+        let now = Utc.with_ymd_and_hms(2024, 10, 19, 18, 0, 0).unwrap();
+        assert_eq!(RateLimit::from_headers(&HeaderMap::new(), now), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ratelimit-requests-limit",
+            HeaderValue::from_static("100"),
+        );
+        headers.insert(
+            "x-ratelimit-requests-remaining",
+            HeaderValue::from_static("42"),
+        );
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("10"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("0"));
+
+        let rate_limit = RateLimit::from_headers(&headers, now).unwrap();
+        assert_eq!(
+            rate_limit,
+            RateLimit {
+                requests_limit: Some(100),
+                requests_remaining: Some(42),
+                minute_limit: Some(10),
+                minute_remaining: Some(0),
+                updated_at: now,
+            }
+        );
+        assert_eq!(rate_limit.remaining_today(now), Some(42));
+
+        // the per minute quota is used up, wait for the rest of the minute
+        let later = now + Duration::seconds(20);
+        assert_eq!(
+            rate_limit.check(0, later)?,
+            Some(std::time::Duration::from_secs(40))
+        );
+        assert_eq!(rate_limit.check(0, now + Duration::seconds(60))?, None);
+
+        assert!(matches!(
+            rate_limit.check(42, later),
+            Err(Error::QuotaError(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    RequestBuilder, Response, StatusCode,
+};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use tokio::time::sleep;
+
+/// Retries after the first attempt
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Delay before the first retry, doubled for each further one
+pub const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest delay between two attempts, also the longest `Retry-After`
+/// waited for
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// When to retry a request and how long to wait in between.
+///
+/// Requests failing to connect, timing out or answered with `429` or a
+/// `5xx` status are retried with an exponential backoff. Each delay is
+/// between half and all of the backoff, so clients failing together do not
+/// retry together. A `Retry-After` of the server is waited for instead,
+/// unless it is longer than `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Rate limited or a failure of the server that may go away
+    #[must_use]
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Network failures that may go away, unlike e.g. an invalid url
+    #[must_use]
+    pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout() || error.is_request()
+    }
+
+    /// How long `Retry-After` asks to wait, as seconds or an HTTP date
+    #[must_use]
+    pub fn retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+        let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+        if let Ok(seconds) = value.parse() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            (date.with_timezone(&Utc) - now)
+                .to_std()
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Delay before retry `attempt`, counted from `0`: the `Retry-After` of
+    /// the server if any, otherwise the jittered backoff. `None` when the
+    /// server asks to wait longer than `max_backoff`.
+    #[must_use]
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_backoff).then_some(retry_after);
+        }
+
+        let backoff = self
+            .backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        let half = backoff / 2;
+        Some(half + half.mul_f64(jitter()))
+    }
+
+    /// Send `request`, retrying it as long as the policy allows. The last
+    /// response is returned as is, whatever its status.
+    /// # Errors
+    ///
+    /// Will return error if the last attempt fails to get a response
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+
+        loop {
+            // the last attempt, or a request with a streamed body that
+            // cannot be sent again
+            let Some(current) = request.try_clone().filter(|_| attempt < self.max_retries) else {
+                return request.send().await;
+            };
+
+            let result = current.send().await;
+            let retry_after = match &result {
+                Ok(response) if Self::is_retryable_status(response.status()) => {
+                    Self::retry_after(response.headers(), Utc::now())
+                }
+                Err(error) if Self::is_retryable_error(error) => None,
+                _ => return result,
+            };
+
+            let Some(delay) = self.delay(attempt, retry_after) else {
+                return result;
+            };
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Random fraction in `0.0..1.0`, good enough to spread out retries
+fn jitter() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use reqwest::{
+        header::{HeaderMap, HeaderValue, RETRY_AFTER},
+        StatusCode,
+    };
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use crate::{football_retry::RetryPolicy, Error};
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+
+        for (attempt, backoff) in [(0, 1), (1, 2), (2, 4), (3, 5), (10, 5)] {
+            let backoff = Duration::from_secs(backoff);
+            let delay = policy.delay(attempt, None).unwrap();
+            assert!(
+                delay >= backoff / 2 && delay <= backoff,
+                "{attempt} {delay:?}"
+            );
+        }
+
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(3))),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(policy.delay(0, Some(Duration::from_secs(60))), None);
+
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(RetryPolicy::is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        let mut headers = HeaderMap::new();
+        assert_eq!(RetryPolicy::retry_after(&headers, now), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(
+            RetryPolicy::retry_after(&headers, now),
+            Some(Duration::from_secs(120))
+        );
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:30 GMT"),
+        );
+        assert_eq!(
+            RetryPolicy::retry_after(&headers, now),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    /// Answer the requests with `responses` in turn, counting them
+    async fn spawn_server(
+        responses: Vec<&'static str>,
+    ) -> Result<(String, Arc<AtomicUsize>), Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/status", listener.local_addr()?);
+        let requests = Arc::new(AtomicUsize::new(0));
+        let count = requests.clone();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await.unwrap();
+                count.fetch_add(1, Ordering::SeqCst);
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        Ok((url, requests))
+    }
+
+    #[tokio::test]
+    async fn test_retry_send() -> Result<(), Error> {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        };
+        let client = reqwest::Client::new();
+
+        let unavailable =
+            "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+        let too_many = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+        let ok = "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}";
+        let not_found = "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+        // recovers after a 503 and a 429
+        let (url, requests) = spawn_server(vec![unavailable, too_many, ok]).await?;
+        let response = policy.send(client.get(&url)).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.text().await?, "{}");
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // a client error is not retried
+        let (url, requests) = spawn_server(vec![not_found]).await?;
+        let response = policy.send(client.get(&url)).await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // gives up after the retries, the last response is returned
+        let (url, requests) = spawn_server(vec![unavailable; 4]).await?;
+        let response = policy.send(client.get(&url)).await?;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        // a refused connection is retried as well
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/status", listener.local_addr()?);
+        drop(listener);
+        let policy = RetryPolicy {
+            max_retries: 1,
+            ..policy
+        };
+        let error = policy.send(client.get(&url)).await.unwrap_err();
+        assert!(error.is_connect());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,852 @@
+use axum::{
+    extract::{Query, State},
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{
+        sse::{Event as SseEvent, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::get,
+    Router,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    future::Future,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{net::TcpListener, sync::broadcast, time::sleep};
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::{
+    football_api::{ClubInfo, FootballApi},
+    football_changes::detect_changes,
+    football_fixtures_data::{FixtureStatus, FootballFixturesData, Timezone},
+    football_output::{ErrorOutput, FixturesOutput, StandingsOutput, TeamsOutput},
+    football_watch::DEFAULT_WATCH_INTERVAL,
+    format_string, Error, StringType,
+};
+
+/// Seconds a response is served from the cache, unless `--cache-ttl` is given
+pub const DEFAULT_CACHE_TTL: u64 = 30;
+
+/// Fixtures returned by `/fixtures/next` without `count`
+const DEFAULT_NEXT_COUNT: u8 = 5;
+
+/// Routes cached at most, any number of `/teams/search?name=...` must not
+/// grow the cache without bound
+const MAX_CACHE_ENTRIES: usize = 1024;
+
+/// Messages a slow stream client may fall behind before it skips ahead
+const STREAM_CAPACITY: usize = 32;
+
+/// Body of the last successful upstream request and when it was made
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    body: StringType,
+    fetched_at: Instant,
+}
+
+/// Responses shared by every client for `ttl`, so that any number of
+/// clients cost one upstream request per `ttl` and route.
+///
+/// Concurrent requests for an expired route wait for a single upstream
+/// request instead of each making their own. Expired routes are evicted
+/// once a new one is cached, and at most `MAX_CACHE_ENTRIES` are kept.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    entries: Mutex<HashMap<StringType, Arc<tokio::sync::Mutex<Option<CacheEntry>>>>>,
+}
+
+impl ResponseCache {
+    /// Entry of `key`, a new route first evicts the routes older than `ttl`
+    /// no request is waiting for. `None` when the cache is full.
+    fn entry(
+        &self,
+        key: &str,
+        ttl: Duration,
+    ) -> Option<Arc<tokio::sync::Mutex<Option<CacheEntry>>>> {
+        let mut entries = self.entries.lock();
+
+        if !entries.contains_key(key) {
+            entries.retain(|_, entry| {
+                // in use by a request, or fetched within `ttl`
+                Arc::strong_count(entry) > 1
+                    || entry.try_lock().map_or(true, |entry| {
+                        entry
+                            .as_ref()
+                            .is_some_and(|cached| cached.fetched_at.elapsed() < ttl)
+                    })
+            });
+            if entries.len() >= MAX_CACHE_ENTRIES {
+                return None;
+            }
+        }
+
+        Some(entries.entry(key.into()).or_default().clone())
+    }
+
+    /// Cached body of `key` if younger than `ttl`, otherwise the body
+    /// returned by `fetch`, which is then cached. Errors are not cached.
+    /// # Errors
+    ///
+    /// Will return error if `fetch` fails
+    pub async fn get_or_fetch<F>(
+        &self,
+        key: &str,
+        ttl: Duration,
+        fetch: F,
+    ) -> Result<StringType, Error>
+    where
+        F: Future<Output = Result<StringType, Error>>,
+    {
+        // a full cache still answers, it just does not keep the body
+        let Some(entry) = self.entry(key, ttl) else {
+            return fetch.await;
+        };
+        let mut entry = entry.lock().await;
+
+        if let Some(cached) = entry.as_ref() {
+            if cached.fetched_at.elapsed() < ttl {
+                return Ok(cached.body.clone());
+            }
+        }
+
+        let body = fetch.await?;
+        *entry = Some(CacheEntry {
+            body: body.clone(),
+            fetched_at: Instant::now(),
+        });
+
+        Ok(body)
+    }
+
+    /// Cache `body` for `key` as if it was just fetched, routes older than
+    /// `ttl` are evicted
+    pub async fn insert(&self, key: &str, ttl: Duration, body: StringType) {
+        let Some(entry) = self.entry(key, ttl) else {
+            return;
+        };
+        *entry.lock().await = Some(CacheEntry {
+            body,
+            fetched_at: Instant::now(),
+        });
+    }
+}
+
+/// Message of the live stream, written out as a server-sent event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamMessage {
+    /// `fixtures`, `change` or `error`
+    pub event: &'static str,
+    /// JSON body of the message
+    pub data: StringType,
+}
+
+impl StreamMessage {
+    fn error(error: &Error) -> Self {
+        let error = format_string!("{error}");
+        let data = serde_json::to_string(&ErrorBody { error: &error }).unwrap_or_default();
+        Self {
+            event: "error",
+            data: data.into(),
+        }
+    }
+}
+
+/// Live fixtures of one club, polled once for all of its subscribers
+struct LiveFeed {
+    sender: broadcast::Sender<StreamMessage>,
+    latest: Mutex<Option<StreamMessage>>,
+}
+
+/// Score and status of each fixture, the parts of a fixture the stream
+/// reports on
+fn live_signature(
+    data: &FootballFixturesData,
+) -> Vec<(u32, Option<usize>, Option<usize>, FixtureStatus)> {
+    data.response
+        .iter()
+        .map(|response| {
+            (
+                response.fixture.id,
+                response.goals.home,
+                response.goals.away,
+                response.fixture.status.short.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Messages of the stream after polling `data`: one `change` per change
+/// since `previous`, then the `fixtures` now live. Nothing when neither the
+/// score nor the status of a fixture changed.
+/// # Errors
+///
+/// Will return error if the api answered `data` with errors, e.g. an
+/// exhausted quota, or if the date of a fixture is malformed
+pub fn live_messages(
+    previous: Option<&FootballFixturesData>,
+    data: &FootballFixturesData,
+    timezone: &Timezone,
+) -> Result<Vec<StreamMessage>, Error> {
+    if let Some(errors) = data.get_api_errors() {
+        return Err(Error::InvalidValue(format_string!(
+            "api answered with errors: {errors}"
+        )));
+    }
+
+    if previous.is_some_and(|previous| live_signature(previous) == live_signature(data)) {
+        return Ok(Vec::new());
+    }
+
+    let mut messages = Vec::new();
+    if let Some(previous) = previous {
+        for change in detect_changes(previous, data) {
+            messages.push(StreamMessage {
+                event: "change",
+                data: serde_json::to_string(&change)?.into(),
+            });
+        }
+    }
+
+    let fixtures = FixturesOutput::from_fixtures(data, None, timezone)?;
+    messages.push(StreamMessage {
+        event: "fixtures",
+        data: serde_json::to_string(&fixtures)?.into(),
+    });
+
+    Ok(messages)
+}
+
+/// Shared by the handlers of `serve`
+pub struct ServerState {
+    api: FootballApi,
+    club_id: u16,
+    timezone: Timezone,
+    cache_ttl: Duration,
+    cache: ResponseCache,
+    poll_interval: Duration,
+    feeds: Mutex<HashMap<u16, Arc<LiveFeed>>>,
+}
+
+impl ServerState {
+    /// `club_id` is used by the fixtures routes without `team`
+    #[must_use]
+    pub fn new(api: FootballApi, club_id: u16, timezone: Timezone, cache_ttl: Duration) -> Self {
+        Self {
+            api,
+            club_id,
+            timezone,
+            cache_ttl,
+            cache: ResponseCache::default(),
+            poll_interval: Duration::from_secs(DEFAULT_WATCH_INTERVAL),
+            feeds: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Poll the live fixtures of a streamed club every `poll_interval`
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Receive the live messages of `team`, starting with the latest
+    /// `fixtures` message. The first subscriber of a club starts polling it,
+    /// polling stops once the last one is gone.
+    fn subscribe(
+        self: &Arc<Self>,
+        team: u16,
+    ) -> (Option<StreamMessage>, broadcast::Receiver<StreamMessage>) {
+        let mut feeds = self.feeds.lock();
+
+        if let Some(feed) = feeds.get(&team) {
+            return (feed.latest.lock().clone(), feed.sender.subscribe());
+        }
+
+        let (sender, receiver) = broadcast::channel(STREAM_CAPACITY);
+        let feed = Arc::new(LiveFeed {
+            sender,
+            latest: Mutex::new(None),
+        });
+        feeds.insert(team, feed.clone());
+        tokio::spawn(self.clone().poll_live(team, feed));
+
+        (None, receiver)
+    }
+
+    async fn poll_live(self: Arc<Self>, team: u16, feed: Arc<LiveFeed>) {
+        let club = ClubInfo::from_parameter(team, 0, "all".into(), "".into());
+        let mut previous = None;
+
+        loop {
+            match self.poll_live_messages(&club, previous.as_ref()).await {
+                Ok((data, messages)) => {
+                    for message in messages {
+                        if message.event == "fixtures" {
+                            self.cache
+                                .insert(&live_key(team), self.cache_ttl, message.data.clone())
+                                .await;
+                            *feed.latest.lock() = Some(message.clone());
+                        }
+                        // no subscriber left is handled below
+                        let _ = feed.sender.send(message);
+                    }
+                    previous = Some(data);
+                }
+                // `previous` and `latest` stay the last good poll, the next
+                // one is compared with it
+                Err(error) => {
+                    let _ = feed.sender.send(StreamMessage::error(&error));
+                }
+            }
+
+            {
+                // under the lock, a new subscriber either finds this feed
+                // still running or starts a new one
+                let mut feeds = self.feeds.lock();
+                if feed.sender.receiver_count() == 0 {
+                    feeds.remove(&team);
+                    return;
+                }
+            }
+
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    async fn poll_live_messages(
+        &self,
+        club: &ClubInfo,
+        previous: Option<&FootballFixturesData>,
+    ) -> Result<(FootballFixturesData, Vec<StreamMessage>), Error> {
+        let data = self.api.get_fixture_data(club).await?;
+        let messages = live_messages(previous, &data, &self.timezone)?;
+        Ok((data, messages))
+    }
+}
+
+/// Cache key of the live fixtures of `team`
+fn live_key(team: u16) -> StringType {
+    format_string!("/fixtures/live?team={team}")
+}
+
+/// Error written out as `{"error": "..."}`
+struct ServerError(StatusCode, StringType);
+
+impl From<Error> for ServerError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InvalidInputError(message) => {
+                Self(StatusCode::BAD_REQUEST, message.trim().into())
+            }
+            error => Self(StatusCode::BAD_GATEWAY, format_string!("{error}")),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let body = serde_json::to_string(&ErrorBody { error: &self.1 }).unwrap_or_default();
+        (self.0, [(CONTENT_TYPE, "application/json")], body).into_response()
+    }
+}
+
+/// Body of a route serving `output`. Errors the api answered with, e.g. an
+/// exhausted quota or a bad key, are an upstream failure: they are answered
+/// with 502 and, being an `Err`, not cached.
+fn output_body<T: Serialize>(output: &T, errors: &[ErrorOutput]) -> Result<StringType, Error> {
+    if !errors.is_empty() {
+        let mut message = StringType::from("api answered with errors: ");
+        for (i, error) in errors.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            message.push_str(&format_string!(
+                "{separator}{}: {}",
+                error.field,
+                error.message
+            ));
+        }
+        return Err(Error::InvalidValue(message));
+    }
+
+    Ok(serde_json::to_string(output)?.into())
+}
+
+fn json_response(body: StringType) -> Response {
+    (
+        StatusCode::OK,
+        [(CONTENT_TYPE, "application/json")],
+        body.to_string(),
+    )
+        .into_response()
+}
+
+#[derive(Deserialize)]
+struct LiveQuery {
+    team: Option<u16>,
+}
+
+#[derive(Deserialize)]
+struct NextQuery {
+    team: Option<u16>,
+    count: Option<u8>,
+}
+
+#[derive(Deserialize)]
+struct TeamsQuery {
+    name: Option<StringType>,
+}
+
+#[derive(Deserialize)]
+struct StandingsQuery {
+    league: Option<u16>,
+    season: Option<u16>,
+}
+
+/// Live fixtures of `team`, `team=0` for every live fixture
+async fn fixtures_live(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<LiveQuery>,
+) -> Result<Response, ServerError> {
+    let team = query.team.unwrap_or(state.club_id);
+    let key = live_key(team);
+
+    let body = state
+        .cache
+        .get_or_fetch(&key, state.cache_ttl, async {
+            let club = ClubInfo::from_parameter(team, 0, "all".into(), "".into());
+            let data = state.api.get_fixture_data(&club).await?;
+            let output = FixturesOutput::from_fixtures(&data, None, &state.timezone)?;
+            output_body(&output, &output.errors)
+        })
+        .await?;
+
+    Ok(json_response(body))
+}
+
+/// Server-sent events of the live fixtures of `team`: a `fixtures` event
+/// right away and whenever a score or status changes, preceded by a
+/// `change` event per kick-off, goal, red card, half-time and full-time
+async fn fixtures_live_stream(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<LiveQuery>,
+) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+    let team = query.team.unwrap_or(state.club_id);
+    let (latest, receiver) = state.subscribe(team);
+
+    // a client too slow to keep up misses messages, the next `fixtures`
+    // message brings it up to date again
+    let messages =
+        tokio_stream::iter(latest).chain(BroadcastStream::new(receiver).filter_map(Result::ok));
+    let events = messages.map(|message| {
+        Ok(SseEvent::default()
+            .event(message.event)
+            .data(message.data.as_str()))
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+/// Next `count` fixtures of `team`
+async fn fixtures_next(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<NextQuery>,
+) -> Result<Response, ServerError> {
+    let team = query.team.unwrap_or(state.club_id);
+    let count = query.count.unwrap_or(DEFAULT_NEXT_COUNT);
+    if count == 0 {
+        return Err(ServerError(
+            StatusCode::BAD_REQUEST,
+            "`count` must be at least 1".into(),
+        ));
+    }
+    let key = format_string!("/fixtures/next?team={team}&count={count}");
+
+    let body = state
+        .cache
+        .get_or_fetch(&key, state.cache_ttl, async {
+            let club = ClubInfo::from_parameter(team, count, "".into(), "".into());
+            let data = state.api.get_fixture_data(&club).await?;
+            let output = FixturesOutput::from_fixtures(&data, None, &state.timezone)?;
+            output_body(&output, &output.errors)
+        })
+        .await?;
+
+    Ok(json_response(body))
+}
+
+/// Clubs matching `name`
+async fn teams_search(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<TeamsQuery>,
+) -> Result<Response, ServerError> {
+    let Some(name) = query.name.filter(|name| !name.trim().is_empty()) else {
+        return Err(ServerError(
+            StatusCode::BAD_REQUEST,
+            "`name` is required".into(),
+        ));
+    };
+    let key = format_string!("/teams/search?name={}", name.to_lowercase());
+
+    let body = state
+        .cache
+        .get_or_fetch(&key, state.cache_ttl, async {
+            let club = ClubInfo::from_parameter(0, 0, "".into(), name.clone());
+            let data = state.api.get_team_data(&club).await?;
+            let output = TeamsOutput::from(&data);
+            output_body(&output, &output.errors)
+        })
+        .await?;
+
+    Ok(json_response(body))
+}
+
+/// League table of `league` in `season`
+async fn standings(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<StandingsQuery>,
+) -> Result<Response, ServerError> {
+    let (Some(league), Some(season)) = (query.league, query.season) else {
+        return Err(ServerError(
+            StatusCode::BAD_REQUEST,
+            "`league` and `season` are required".into(),
+        ));
+    };
+    let key = format_string!("/standings?league={league}&season={season}");
+
+    let body = state
+        .cache
+        .get_or_fetch(&key, state.cache_ttl, async {
+            let data = state.api.get_standings_data(league, season).await?;
+            let output = StandingsOutput::from(&data);
+            output_body(&output, &output.errors)
+        })
+        .await?;
+
+    Ok(json_response(body))
+}
+
+/// Routes of `serve`:
+///
+/// * `GET /fixtures/live?team=ID`, `team=0` for every live match
+/// * `GET /fixtures/live/stream?team=ID`, the same as server-sent events
+///   pushed whenever a score or status changes
+/// * `GET /fixtures/next?team=ID&count=N`
+/// * `GET /teams/search?name=NAME`
+/// * `GET /standings?league=ID&season=YEAR`
+///
+/// Fixtures follow the schema of `FixturesOutput`, clubs the one of
+/// `TeamsOutput` and league tables the one of `StandingsOutput`, `team`
+/// defaults to the configured club.
+pub fn router(state: ServerState) -> Router {
+    Router::new()
+        .route("/fixtures/live", get(fixtures_live))
+        .route("/fixtures/live/stream", get(fixtures_live_stream))
+        .route("/fixtures/next", get(fixtures_next))
+        .route("/teams/search", get(teams_search))
+        .route("/standings", get(standings))
+        .with_state(Arc::new(state))
+}
+
+/// Serve the routes of `router` on `bind` until the process is stopped
+/// # Errors
+///
+/// Will return error if `bind` cannot be listened on
+pub async fn serve(bind: SocketAddr, state: ServerState) -> Result<(), Error> {
+    let listener = TcpListener::bind(bind).await?;
+    axum::serve(listener, router(state)).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use parking_lot::Mutex;
+    use reqwest::StatusCode;
+    use std::sync::Arc;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        sync::broadcast,
+    };
+
+    use crate::{
+        football_api::FootballApi,
+        football_fixtures_data::{FixtureStatus, FootballErrors, FootballFixturesData, Timezone},
+        football_server::{
+            live_messages, router, LiveFeed, ResponseCache, ServerState, StreamMessage,
+            MAX_CACHE_ENTRIES,
+        },
+        Error, StringType,
+    };
+
+    #[tokio::test]
+    async fn test_response_cache() -> Result<(), Error> {
+        let cache = ResponseCache::default();
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            let count = fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(StringType::from(count.to_string().as_str()))
+        };
+
+        let ttl = Duration::from_secs(60);
+        assert_eq!(cache.get_or_fetch("/a", ttl, fetch()).await?, "1");
+        assert_eq!(cache.get_or_fetch("/a", ttl, fetch()).await?, "1");
+        assert_eq!(cache.get_or_fetch("/b", ttl, fetch()).await?, "2");
+
+        // expired
+        assert_eq!(
+            cache.get_or_fetch("/a", Duration::ZERO, fetch()).await?,
+            "3"
+        );
+
+        // errors are not cached
+        let failing = async { Err(Error::InvalidValue("upstream".into())) };
+        assert!(cache.get_or_fetch("/c", ttl, failing).await.is_err());
+        assert_eq!(cache.get_or_fetch("/c", ttl, fetch()).await?, "4");
+
+        // concurrent requests share one fetch
+        let (first, second) = tokio::join!(
+            cache.get_or_fetch("/d", ttl, fetch()),
+            cache.get_or_fetch("/d", ttl, fetch())
+        );
+        assert_eq!((first?, second?), ("5".into(), "5".into()));
+        assert_eq!(fetches.load(Ordering::SeqCst), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_eviction() -> Result<(), Error> {
+        let cache = ResponseCache::default();
+        let fetch = |body: &str| {
+            let body = StringType::from(body);
+            async move { Ok(body) }
+        };
+
+        // every search a client makes is a new route
+        let ttl = Duration::from_secs(60);
+        for i in 0..MAX_CACHE_ENTRIES {
+            let key = format!("/teams/search?name={i}");
+            cache.get_or_fetch(&key, ttl, fetch("cached")).await?;
+        }
+        assert_eq!(cache.entries.lock().len(), MAX_CACHE_ENTRIES);
+
+        // full, answered without being cached
+        assert_eq!(cache.get_or_fetch("/a", ttl, fetch("a")).await?, "a");
+        assert_eq!(cache.get_or_fetch("/a", ttl, fetch("b")).await?, "b");
+        assert_eq!(cache.entries.lock().len(), MAX_CACHE_ENTRIES);
+
+        // a new route evicts the expired ones
+        assert_eq!(
+            cache.get_or_fetch("/a", Duration::ZERO, fetch("c")).await?,
+            "c"
+        );
+        assert_eq!(cache.entries.lock().len(), 1);
+        cache.insert("/b", ttl, "b".into()).await;
+        assert_eq!(cache.get_or_fetch("/b", ttl, fetch("d")).await?, "b");
+        assert_eq!(cache.entries.lock().len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_router_bad_requests() -> Result<(), Error> {
+        let state = ServerState::new(
+            FootballApi::new("api_key", "test.local"),
+            529,
+            Timezone::Named(chrono_tz::UTC),
+            Duration::from_secs(60),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        tokio::spawn(async move { axum::serve(listener, router(state)).await });
+
+        let client = reqwest::Client::new();
+        for (path, error) in [
+            ("/teams/search", "`name` is required"),
+            (
+                "/standings?league=140",
+                "`league` and `season` are required",
+            ),
+            ("/fixtures/next?count=0", "`count` must be at least 1"),
+        ] {
+            let response = client.get(format!("{url}{path}")).send().await?;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body: serde_json::Value = response.json().await?;
+            assert_eq!(body["error"], error);
+        }
+
+        let response = client.get(format!("{url}/unknown")).send().await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_router_api_errors() -> Result<(), Error> {
+        // stand-in of the api with its daily quota used up
+        let upstream = TcpListener::bind("127.0.0.1:0").await?;
+        let api_endpoint = format!("http://{}/v3", upstream.local_addr()?);
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            let body = r#"{"get": "teams", "parameters": {"name": "arsenal"},
+                "errors": {"requests": "You have reached the request limit for the day"},
+                "results": 0, "paging": {"current": 1, "total": 1}, "response": []}"#;
+            while let Ok((mut stream, _)) = upstream.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                     content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let state = ServerState::new(
+            FootballApi::new("api_key", &api_endpoint),
+            529,
+            Timezone::Named(chrono_tz::UTC),
+            Duration::from_secs(60),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        tokio::spawn(async move { axum::serve(listener, router(state)).await });
+
+        let client = reqwest::Client::new();
+        for _ in 0..2 {
+            let response = client
+                .get(format!("{url}/teams/search?name=arsenal"))
+                .send()
+                .await?;
+            assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+            let body: serde_json::Value = response.json().await?;
+            assert!(body["error"]
+                .as_str()
+                .is_some_and(|error| error.contains("request limit for the day")));
+        }
+
+        // not cached, the api is asked again
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_live_messages() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let previous: FootballFixturesData = serde_json::from_str(buf)?;
+        let timezone = Timezone::Named(chrono_tz::UTC);
+
+        let messages = live_messages(None, &previous, &timezone)?;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].event, "fixtures");
+        let fixtures: serde_json::Value = serde_json::from_str(&messages[0].data)?;
+        assert_eq!(fixtures["fixtures"][0]["id"], 239625);
+
+        // nothing changed, nothing to push
+        assert!(live_messages(Some(&previous), &previous, &timezone)?.is_empty());
+
+        let mut current = previous.clone();
+        current.response[0].goals.home = Some(1);
+        let messages = live_messages(Some(&previous), &current, &timezone)?;
+        let events: Vec<_> = messages.iter().map(|message| message.event).collect();
+        assert_eq!(events, ["change", "fixtures"]);
+        let change: serde_json::Value = serde_json::from_str(&messages[0].data)?;
+        assert_eq!(change["type"], "goal");
+        assert_eq!(change["team"], "Barcelona");
+
+        // a status change without a change event is still pushed
+        let previous = current.clone();
+        current.response[0].fixture.status.short = FixtureStatus::SecondHalf;
+        let messages = live_messages(Some(&previous), &current, &timezone)?;
+        let events: Vec<_> = messages.iter().map(|message| message.event).collect();
+        assert_eq!(events, ["fixtures"]);
+
+        // a rate limit mid-match is an error, not the end of every match
+        let errored = FootballFixturesData {
+            errors: FootballErrors::WithMessages(
+                [("rateLimit".into(), "Too many requests".into())].into(),
+            ),
+            ..FootballFixturesData::default()
+        };
+        assert!(matches!(
+            live_messages(Some(&current), &errored, &timezone),
+            Err(Error::InvalidValue(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_live_stream() -> Result<(), Error> {
+        let state = ServerState::new(
+            FootballApi::new("api_key", "test.local"),
+            529,
+            Timezone::Named(chrono_tz::UTC),
+            Duration::from_secs(60),
+        );
+
+        // a feed already polled, so the stream does not reach the api
+        let (sender, _) = broadcast::channel(8);
+        let latest = StreamMessage {
+            event: "fixtures",
+            data: r#"{"fixtures":[]}"#.into(),
+        };
+        let feed = Arc::new(LiveFeed {
+            sender: sender.clone(),
+            latest: Mutex::new(Some(latest)),
+        });
+        state.feeds.lock().insert(529, feed);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        tokio::spawn(async move { axum::serve(listener, router(state)).await });
+
+        let mut response = reqwest::get(format!("{url}/fixtures/live/stream")).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"].to_str().unwrap(),
+            "text/event-stream"
+        );
+
+        let mut received = String::new();
+        while !received.ends_with("\n\n") {
+            let chunk = response.chunk().await?.unwrap();
+            received.push_str(&String::from_utf8_lossy(&chunk));
+        }
+        assert_eq!(received, "event: fixtures\ndata: {\"fixtures\":[]}\n\n");
+
+        sender
+            .send(StreamMessage {
+                event: "change",
+                data: r#"{"type":"goal"}"#.into(),
+            })
+            .unwrap();
+        received.clear();
+        while !received.ends_with("\n\n") {
+            let chunk = response.chunk().await?.unwrap();
+            received.push_str(&String::from_utf8_lossy(&chunk));
+        }
+        assert_eq!(received, "event: change\ndata: {\"type\":\"goal\"}\n\n");
+
+        Ok(())
+    }
+}
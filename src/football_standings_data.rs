@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Write};
+
+use crate::{
+    football_fixtures_data::{Paging, Parameters},
+    StringType,
+};
+
+/// Team names longer than this are truncated in the standings table
+const TEAM_NAME_WIDTH: usize = 24;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    pub id: u16,
+    pub name: StringType,
+    pub logo: StringType,
+}
+
+#[derive(Serialize, Default, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Goals {
+    #[serde(rename = "for")]
+    pub scored: Option<u16>,
+    pub against: Option<u16>,
+}
+
+#[derive(Serialize, Default, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub played: Option<u16>,
+    pub win: Option<u16>,
+    pub draw: Option<u16>,
+    pub lose: Option<u16>,
+    pub goals: Goals,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Standing {
+    pub rank: u16,
+    pub team: Team,
+    pub points: i16,
+
+    #[serde(rename = "goalsDiff")]
+    pub goals_diff: i16,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<StringType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<StringType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StringType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<StringType>,
+
+    pub all: Record,
+    pub home: Record,
+    pub away: Record,
+    pub update: StringType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct League {
+    pub id: u16,
+    pub name: StringType,
+    pub country: StringType,
+    pub logo: StringType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+    pub season: u16,
+
+    /// One table per group, most leagues only have a single group
+    pub standings: Vec<Vec<Standing>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Response {
+    pub league: League,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FootballStandingsErrors {
+    Empty(Vec<Option<serde_json::Value>>),
+    WithMessages(HashMap<String, String>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootballStandingsData {
+    pub get: StringType,
+
+    #[serde(flatten)]
+    pub parameters: Parameters,
+
+    pub errors: FootballStandingsErrors,
+    pub results: usize,
+    pub paging: Paging,
+    pub response: Vec<Response>,
+}
+
+impl Default for FootballStandingsData {
+    fn default() -> Self {
+        Self {
+            get: "".into(),
+            parameters: Parameters::League("".into()),
+            errors: FootballStandingsErrors::Empty(Vec::new()),
+            results: 0,
+            paging: Paging::default(),
+            response: Vec::new(),
+        }
+    }
+}
+
+impl FootballStandingsData {
+    /// Write out a ranked table for every group of the league, the row of
+    /// `club_id` is marked with a leading `*`.
+    /// ```
+    /// use footballscore::football_standings_data::FootballStandingsData;
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/standings.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballStandingsData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_standings_table(529);
+    ///
+    /// assert!(buf.starts_with("La Liga 2023 (Spain)"));
+    /// assert!(buf.contains("*  2  Barcelona"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_standings_table(&self, club_id: u16) -> StringType {
+        let mut output = StringType::from("");
+
+        if let Some(response) = self.response.first() {
+            let league = &response.league;
+
+            let _ = writeln!(
+                output,
+                "{} {} ({})",
+                &league.name, league.season, &league.country
+            );
+
+            for group in &league.standings {
+                if league.standings.len() > 1 {
+                    if let Some(group_name) = group.first().and_then(|s| s.group.as_ref()) {
+                        let _ = writeln!(output, "\n{group_name}");
+                    }
+                }
+
+                let _ = writeln!(
+                    output,
+                    "{:>4}  {:<TEAM_NAME_WIDTH$} {:>3} {:>3} {:>3} {:>3} {:>4} {:>4}  Form",
+                    "#", "Team", "P", "W", "D", "L", "GD", "Pts"
+                );
+
+                for standing in group {
+                    Self::write_standing(&mut output, standing, club_id);
+                }
+            }
+        } else if let FootballStandingsErrors::WithMessages(error_messages) = &self.errors {
+            let mut buffer = String::with_capacity(500);
+
+            let print_error = |output: &mut String, field_name: &str, error: &str| {
+                let _ = writeln!(output, "Error: {field_name} - {error}");
+            };
+
+            for field_name in &["access", "token", "requests", "league", "season"] {
+                if let Some(error) = error_messages.get(*field_name) {
+                    print_error(&mut buffer, field_name, error);
+                }
+            }
+
+            if !buffer.is_empty() {
+                output.push_str(&buffer);
+            }
+        } else {
+            output.push_str("League standings are unavailable");
+        }
+
+        output
+    }
+
+    fn write_standing(output: &mut StringType, standing: &Standing, club_id: u16) {
+        let marker = if standing.team.id == club_id {
+            '*'
+        } else {
+            ' '
+        };
+        let team_name: String = standing.team.name.chars().take(TEAM_NAME_WIDTH).collect();
+        let record = &standing.all;
+
+        let _ = writeln!(
+            output,
+            "{marker}{:>3}  {team_name:<TEAM_NAME_WIDTH$} {:>3} {:>3} {:>3} {:>3} {:>+4} {:>4}  {}",
+            standing.rank,
+            record.played.unwrap_or_default(),
+            record.win.unwrap_or_default(),
+            record.draw.unwrap_or_default(),
+            record.lose.unwrap_or_default(),
+            standing.goals_diff,
+            standing.points,
+            standing.form.as_ref().map_or("", StringType::as_str),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_standings_data::{
+            FootballStandingsData, FootballStandingsErrors, Paging, Parameters,
+        },
+        Error,
+    };
+    use log::info;
+
+    #[test]
+    fn test_football_standings_data() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/standings.json");
+        let data: FootballStandingsData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::League("140".into()));
+
+        let buf = data.get_standings_table(529);
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(lines[0], "La Liga 2023 (Spain)");
+        assert!(lines[1].starts_with("   #  Team"));
+        assert!(lines[2].starts_with("   1  Real Madrid"));
+        assert!(lines[2].ends_with(" 38  29   8   1  +61   95  DWWWW"));
+        assert!(lines[3].starts_with("*  2  Barcelona"));
+        assert!(lines[4].starts_with("  20  Granada CF"));
+        assert!(lines[4].contains(" -33   21  LDLLL"));
+
+        let buf = data.get_standings_table(0);
+        assert!(!buf.contains('*'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_football_data() -> Result<(), Error> {
+        let default_data = FootballStandingsData::default();
+
+        assert_eq!(
+            default_data.get,
+            "".to_string(),
+            "Expected default get value"
+        );
+
+        assert_eq!(
+            default_data.parameters,
+            Parameters::League("".into()),
+            "Expected default parameters"
+        );
+
+        if let FootballStandingsErrors::Empty(empty_errors) = &default_data.errors {
+            assert!(
+                empty_errors.is_empty(),
+                "Expected no errors in default data"
+            );
+        } else {
+            panic!("Unexpected non-empty errors variant in default data");
+        }
+
+        assert_eq!(default_data.results, 0, "Expected default results value");
+
+        assert_eq!(
+            default_data.paging,
+            Paging::default(),
+            "Expected default paging"
+        );
+
+        assert!(
+            default_data.response.is_empty(),
+            "Expected no response data in default"
+        );
+
+        assert_eq!(
+            default_data.get_standings_table(529),
+            "League standings are unavailable"
+        );
+
+        Ok(())
+    }
+}
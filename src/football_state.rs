@@ -0,0 +1,340 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    config::Config,
+    football_changes::{detect_changes, FixtureChange},
+    football_fixtures_data::{FootballFixturesData, Response},
+    format_string, Error, StringType,
+};
+
+/// Snapshots not updated for this long are removed, their fixtures are over
+const MAX_SNAPSHOT_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Write then rename, a reader never sees half a file
+fn write_atomic(path: &Path, buf: &[u8]) -> Result<(), Error> {
+    let tmp_path = path.with_extension(format_string!("json.{}.tmp", std::process::id()));
+    fs::write(&tmp_path, buf)?;
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+/// Last seen snapshot of each fixture, one JSON file per `Fixture::id`, so
+/// changes are detected across runs of the CLI. The fixtures live at the
+/// last run are listed per scope, i.e. per query, so a fixture dropping out
+/// of the `live=all` query is still compared with its last snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureStore {
+    dir: PathBuf,
+    scope: StringType,
+}
+
+impl FixtureStore {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            scope: StringType::default(),
+        }
+    }
+
+    /// Keep the fixtures live at the last run apart per `scope`, e.g. per
+    /// `ClubInfo`, the fixtures of one club are not over because another
+    /// club is asked for
+    #[must_use]
+    pub fn with_scope(mut self, scope: impl fmt::Display) -> Self {
+        self.scope = format_string!("{scope}");
+        self
+    }
+
+    /// Store in `STATE_DIR` if set, otherwise in the default directory
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.state_dir.clone().unwrap_or_else(Self::default_dir))
+    }
+
+    /// `$XDG_STATE_HOME/footballscore`, i.e. `~/.local/state/footballscore`,
+    /// or the local data directory where there is no state directory
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        dirs::state_dir()
+            .or_else(dirs::data_local_dir)
+            .unwrap_or_else(|| "./".into())
+            .join("footballscore")
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn snapshot_path(&self, fixture_id: u32) -> PathBuf {
+        self.dir
+            .join(format_string!("fixture-{fixture_id}.json").as_str())
+    }
+
+    /// e.g. `live-529-0-0-all-.json` for the scope `529,0,0,all,`
+    fn live_path(&self) -> PathBuf {
+        let mut name = String::from("live");
+        if !self.scope.is_empty() {
+            name.push('-');
+            name.extend(
+                self.scope
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' }),
+            );
+        }
+        name.push_str(".json");
+        self.dir.join(name)
+    }
+
+    /// Fixtures live at the last run in this scope
+    fn load_live(&self) -> Vec<u32> {
+        fs::read(self.live_path())
+            .ok()
+            .and_then(|buf| serde_json::from_slice(&buf).ok())
+            .unwrap_or_default()
+    }
+
+    /// Last seen snapshots of the fixtures in `data` and of the fixtures
+    /// live at the last run, fixtures never seen before are left out
+    /// # Errors
+    ///
+    /// Will return error if a snapshot cannot be read
+    pub fn load(&self, data: &FootballFixturesData) -> Result<FootballFixturesData, Error> {
+        let mut previous = FootballFixturesData::default();
+
+        let mut fixture_ids: Vec<u32> = data
+            .response
+            .iter()
+            .map(|response| response.fixture.id)
+            .collect();
+        for fixture_id in self.load_live() {
+            if !fixture_ids.contains(&fixture_id) {
+                fixture_ids.push(fixture_id);
+            }
+        }
+
+        for fixture_id in fixture_ids {
+            let path = self.snapshot_path(fixture_id);
+            if !path.exists() {
+                continue;
+            }
+            // a snapshot cut short by a crash counts as never seen
+            if let Ok(snapshot) = serde_json::from_slice::<Response>(&fs::read(path)?) {
+                previous.response.push(snapshot);
+            }
+        }
+
+        Ok(previous)
+    }
+
+    /// Keep the fixtures of `data` as the last seen snapshots, and the live
+    /// ones as the fixtures live at the last run, then remove the snapshots
+    /// of fixtures not seen for a month
+    /// # Errors
+    ///
+    /// Will return error if a snapshot cannot be written
+    pub fn save(&self, data: &FootballFixturesData) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)?;
+
+        for response in &data.response {
+            write_atomic(
+                &self.snapshot_path(response.fixture.id),
+                &serde_json::to_vec(response)?,
+            )?;
+        }
+
+        let live: Vec<u32> = data
+            .response
+            .iter()
+            .filter(|response| response.fixture.status.short.is_live())
+            .map(|response| response.fixture.id)
+            .collect();
+        write_atomic(&self.live_path(), &serde_json::to_vec(&live)?)?;
+
+        self.remove_stale_snapshots()
+    }
+
+    fn remove_stale_snapshots(&self) -> Result<(), Error> {
+        let now = SystemTime::now();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let is_snapshot = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with("fixture-") || name.starts_with("live-"));
+            let age = entry
+                .metadata()?
+                .modified()
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok());
+
+            if is_snapshot && age.is_some_and(|age| age > MAX_SNAPSHOT_AGE) {
+                fs::remove_file(entry.path())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// What changed in the fixtures of `data` since they were last seen,
+    /// `data` is kept as the new snapshot
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData, football_state::FixtureStore,
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// # let state_dir = tempfile::tempdir()?;
+    /// let mut data: FootballFixturesData = serde_json::from_str(&buf)?;
+    /// let store = FixtureStore::new(state_dir.path());
+    ///
+    /// // a live fixture never seen before has just kicked off
+    /// let changes = store.changes_since_last_run(&data)?;
+    /// assert_eq!(changes[0].to_string(), "Kick-off: Barcelona 0 - 1 Arsenal");
+    ///
+    /// data.response[0].goals.home = Some(1);
+    /// let changes = store.changes_since_last_run(&data)?;
+    ///
+    /// assert_eq!(changes[0].to_string(), "Goal for Barcelona: Barcelona 1 - 1 Arsenal");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the api answered `data` with errors, the
+    /// snapshots are then left as they are, or if the snapshots cannot be
+    /// read or written
+    pub fn changes_since_last_run(
+        &self,
+        data: &FootballFixturesData,
+    ) -> Result<Vec<FixtureChange>, Error> {
+        if let Some(errors) = data.get_api_errors() {
+            return Err(Error::InvalidValue(format_string!(
+                "api answered with errors, no changes detected: {errors}"
+            )));
+        }
+
+        let previous = self.load(data)?;
+        let changes = detect_changes(&previous, data);
+        self.save(data)?;
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use tempfile::tempdir;
+
+    use crate::{
+        football_changes::ChangeKind,
+        football_fixtures_data::{FixtureStatus, FootballErrors, FootballFixturesData},
+        football_state::FixtureStore,
+        Error,
+    };
+
+    #[test]
+    fn test_fixture_store() -> Result<(), Error> {
+        let state_dir = tempdir()?;
+        let store = FixtureStore::new(state_dir.path().join("footballscore"));
+
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        assert!(store.load(&data)?.response.is_empty());
+        assert!(store.changes_since_last_run(&data)?.is_empty());
+        assert_eq!(fs::read_dir(store.dir())?.count(), 4);
+        assert!(store.dir().join("fixture-1035000.json").exists());
+
+        // the snapshot survives a new store, i.e. a new run
+        let store = FixtureStore::new(store.dir());
+        assert_eq!(store.load(&data)?.response, data.response);
+
+        data.response[2].fixture.status.short = FixtureStatus::Postponed;
+        data.response[1].goals.away = Some(2);
+        let changes = store.changes_since_last_run(&data)?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].kind,
+            ChangeKind::Goal {
+                team: "Arsenal".into()
+            }
+        );
+        assert!(store.changes_since_last_run(&data)?.is_empty());
+
+        // an unreadable snapshot is treated as never seen
+        fs::write(store.dir().join("fixture-1035000.json"), "{")?;
+        assert_eq!(store.load(&data)?.response.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture_store_live_fixture_over() -> Result<(), Error> {
+        let state_dir = tempdir()?;
+        let store = FixtureStore::new(state_dir.path()).with_scope("529,0,0,all,");
+        let other_club = FixtureStore::new(state_dir.path()).with_scope("42,0,0,all,");
+
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let live: FootballFixturesData = serde_json::from_str(buf)?;
+        let over = FootballFixturesData::default();
+
+        let changes = store.changes_since_last_run(&live)?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::KickOff);
+        assert!(state_dir.path().join("live-529-0-0-all-.json").exists());
+
+        // the fixture of one club is not over because another is asked for
+        assert!(other_club.changes_since_last_run(&over)?.is_empty());
+
+        // `live=all` no longer returns the fixture once it is over
+        let changes = store.changes_since_last_run(&over)?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::FullTime);
+        assert!(store.changes_since_last_run(&over)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture_store_api_errors() -> Result<(), Error> {
+        let state_dir = tempdir()?;
+        let store = FixtureStore::new(state_dir.path()).with_scope("529,0,0,all,");
+
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let live: FootballFixturesData = serde_json::from_str(buf)?;
+        assert_eq!(store.changes_since_last_run(&live)?.len(), 1);
+
+        // the quota is used up mid-match, the api answers with no fixtures
+        let errored = FootballFixturesData {
+            errors: FootballErrors::WithMessages(
+                [("requests".into(), "request limit reached".into())].into(),
+            ),
+            ..FootballFixturesData::default()
+        };
+        assert!(matches!(
+            store.changes_since_last_run(&errored),
+            Err(Error::InvalidValue(_))
+        ));
+
+        // the fixture is still live once the api answers again
+        assert!(store.changes_since_last_run(&live)?.is_empty());
+        let changes = store.changes_since_last_run(&FootballFixturesData::default())?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::FullTime);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,398 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{collections::HashMap, fmt, fmt::Write};
+
+use crate::{
+    football_fixtures_data::{Paging, Parameters, Teams},
+    StringType,
+};
+
+/// Width of the home vs away comparison bar
+const BAR_WIDTH: usize = 20;
+
+/// Width of the statistic label column
+const LABEL_WIDTH: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    pub id: u16,
+    pub name: StringType,
+    pub logo: StringType,
+}
+
+/// Statistic value normalised from the api, which mixes integers, strings
+/// such as `"55%"` or `"1.24"` and `null`
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(untagged)]
+pub enum StatisticValue {
+    #[default]
+    Missing,
+    Count(u32),
+    Percentage(f64),
+    Decimal(f64),
+}
+
+impl StatisticValue {
+    /// Numeric value, a missing statistic counts as `0`
+    #[must_use]
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Missing => 0.0,
+            Self::Count(count) => count as f64,
+            Self::Percentage(value) | Self::Decimal(value) => value,
+        }
+    }
+
+    fn from_str_value(value: &str) -> Self {
+        let value = value.trim();
+
+        if let Some(percentage) = value.strip_suffix('%') {
+            return percentage
+                .trim()
+                .parse()
+                .map_or(Self::Missing, Self::Percentage);
+        }
+
+        if let Ok(count) = value.parse() {
+            return Self::Count(count);
+        }
+
+        value.parse().map_or(Self::Missing, Self::Decimal)
+    }
+}
+
+impl fmt::Display for StatisticValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("-"),
+            Self::Count(count) => write!(f, "{count}"),
+            Self::Percentage(value) => write!(f, "{value}%"),
+            Self::Decimal(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatisticValue {
+    fn deserialize<D>(deserializer: D) -> Result<StatisticValue, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value: serde_json::Value = Deserialize::deserialize(deserializer)?;
+
+        let value = match value {
+            serde_json::Value::Number(number) => match number.as_u64() {
+                Some(count) => u32::try_from(count).map_or(Self::Missing, Self::Count),
+                None => number.as_f64().map_or(Self::Missing, Self::Decimal),
+            },
+            serde_json::Value::String(value) => Self::from_str_value(&value),
+            _ => Self::Missing,
+        };
+
+        Ok(value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Statistic {
+    #[serde(rename = "type")]
+    pub kind: StringType,
+    pub value: StatisticValue,
+}
+
+impl Statistic {
+    /// Human readable name, the api uses `snake_case` for some of them (e.g.
+    /// `expected_goals`)
+    #[must_use]
+    pub fn label(&self) -> StringType {
+        let mut label = StringType::from("");
+
+        for (idx, word) in self.kind.split('_').enumerate() {
+            if idx > 0 {
+                label.push(' ');
+            }
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                label.extend(first.to_uppercase());
+                label.push_str(chars.as_str());
+            }
+        }
+
+        label
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TeamStatistics {
+    pub team: Team,
+    pub statistics: Vec<Statistic>,
+}
+
+impl TeamStatistics {
+    /// Value of a statistic by its api `type`, e.g. `Ball Possession`
+    #[must_use]
+    pub fn get(&self, kind: &str) -> StatisticValue {
+        self.statistics
+            .iter()
+            .find(|statistic| statistic.kind == kind)
+            .map_or(StatisticValue::Missing, |statistic| statistic.value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FootballStatisticsErrors {
+    Empty(Vec<Option<serde_json::Value>>),
+    WithMessages(HashMap<String, String>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootballStatisticsData {
+    pub get: StringType,
+
+    #[serde(flatten)]
+    pub parameters: Parameters,
+
+    pub errors: FootballStatisticsErrors,
+    pub results: usize,
+    pub paging: Paging,
+    pub response: Vec<TeamStatistics>,
+}
+
+impl Default for FootballStatisticsData {
+    fn default() -> Self {
+        Self {
+            get: "".into(),
+            parameters: Parameters::Fixture("".into()),
+            errors: FootballStatisticsErrors::Empty(Vec::new()),
+            results: 0,
+            paging: Paging::default(),
+            response: Vec::new(),
+        }
+    }
+}
+
+impl FootballStatisticsData {
+    /// Statistics of the given team, if the api returned them
+    #[must_use]
+    pub fn get_team_statistics(&self, team_id: u16) -> Option<&TeamStatistics> {
+        self.response.iter().find(|stats| stats.team.id == team_id)
+    }
+
+    /// Write out a side-by-side comparison of the home and away statistics,
+    /// the bar is filled with `#` for the home share and `-` for the away
+    /// share.
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData,
+    ///     football_statistics_data::FootballStatisticsData,
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let fixtures: FootballFixturesData = serde_json::from_str(&buf)?;
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/statistics.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballStatisticsData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_statistics_comparison(&fixtures.response[0].teams);
+    ///
+    /// assert!(buf.starts_with("Statistics: Barcelona vs Arsenal\n"));
+    /// assert!(buf.contains("Ball Possession"));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_statistics_comparison(&self, teams: &Teams) -> StringType {
+        let mut output = StringType::from("");
+
+        let home = self.get_team_statistics(teams.home.id);
+        let away = self.get_team_statistics(teams.away.id);
+
+        if let (Some(home), Some(away)) = (home, away) {
+            let _ = writeln!(
+                output,
+                "Statistics: {} vs {}",
+                &teams.home.name, &teams.away.name
+            );
+
+            for statistic in &home.statistics {
+                let home_value = statistic.value;
+                let away_value = away.get(&statistic.kind);
+
+                let _ = writeln!(
+                    output,
+                    "{:<LABEL_WIDTH$}{:>6} [{}] {}",
+                    statistic.label(),
+                    home_value.to_string(),
+                    Self::comparison_bar(home_value.as_f64(), away_value.as_f64()),
+                    away_value
+                );
+            }
+        } else if let FootballStatisticsErrors::WithMessages(error_messages) = &self.errors {
+            let mut buffer = String::with_capacity(500);
+
+            let print_error = |output: &mut String, field_name: &str, error: &str| {
+                let _ = writeln!(output, "Error: {field_name} - {error}");
+            };
+
+            for field_name in &["access", "token", "requests", "fixture"] {
+                if let Some(error) = error_messages.get(*field_name) {
+                    print_error(&mut buffer, field_name, error);
+                }
+            }
+
+            if !buffer.is_empty() {
+                output.push_str(&buffer);
+            }
+        } else {
+            output.push_str("Statistics are not available yet\n");
+        }
+
+        output
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn comparison_bar(home: f64, away: f64) -> String {
+        let total = home + away;
+
+        if total <= 0.0 {
+            return " ".repeat(BAR_WIDTH);
+        }
+
+        let home_width = ((home / total) * BAR_WIDTH as f64).round() as usize;
+        let home_width = home_width.min(BAR_WIDTH);
+
+        format!(
+            "{}{}",
+            "#".repeat(home_width),
+            "-".repeat(BAR_WIDTH - home_width)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{Away, Home, Teams},
+        football_statistics_data::{
+            FootballStatisticsData, FootballStatisticsErrors, Paging, Parameters, StatisticValue,
+        },
+        Error,
+    };
+    use log::info;
+
+    #[test]
+    fn test_football_statistics_data() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/statistics.json");
+        let data: FootballStatisticsData = serde_json::from_str(buf)?;
+
+        assert_eq!(data.parameters, Parameters::Fixture("239625".into()));
+
+        let home = data.get_team_statistics(529);
+        let away = data.get_team_statistics(33);
+        assert!(home.is_some() && away.is_some());
+
+        if let (Some(home), Some(away)) = (home, away) {
+            assert_eq!(home.get("Shots on Goal"), StatisticValue::Count(5));
+            assert_eq!(home.get("Offsides"), StatisticValue::Missing);
+            assert_eq!(
+                home.get("Ball Possession"),
+                StatisticValue::Percentage(61.0)
+            );
+            assert_eq!(home.get("expected_goals"), StatisticValue::Decimal(1.84));
+            assert_eq!(away.get("Total passes"), StatisticValue::Count(318));
+            assert_eq!(away.get("Unknown"), StatisticValue::Missing);
+        }
+
+        let teams = Teams {
+            home: Home {
+                id: 529,
+                name: "Barcelona".into(),
+                ..Home::default()
+            },
+            away: Away {
+                id: 33,
+                name: "Arsenal".into(),
+                ..Away::default()
+            },
+        };
+
+        let buf = data.get_statistics_comparison(&teams);
+        info!("{buf}");
+
+        let lines: Vec<&str> = buf.lines().collect();
+        assert_eq!(lines[0], "Statistics: Barcelona vs Arsenal");
+        assert_eq!(
+            lines[1],
+            "Shots on Goal            5 [#############-------] 3"
+        );
+        assert_eq!(
+            lines[6],
+            "Offsides                 - [--------------------] 3"
+        );
+        assert_eq!(
+            lines[7],
+            "Ball Possession        61% [############--------] 39%"
+        );
+        assert_eq!(
+            lines[13],
+            "Expected Goals        1.84 [###############-----] 0.67"
+        );
+
+        let json = serde_json::to_value(&data.response[0].statistics[6])?;
+        assert_eq!(json["value"], 61.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_football_data() -> Result<(), Error> {
+        let default_data = FootballStatisticsData::default();
+
+        assert_eq!(
+            default_data.get,
+            "".to_string(),
+            "Expected default get value"
+        );
+
+        assert_eq!(
+            default_data.parameters,
+            Parameters::Fixture("".into()),
+            "Expected default parameters"
+        );
+
+        if let FootballStatisticsErrors::Empty(empty_errors) = &default_data.errors {
+            assert!(
+                empty_errors.is_empty(),
+                "Expected no errors in default data"
+            );
+        } else {
+            panic!("Unexpected non-empty errors variant in default data");
+        }
+
+        assert_eq!(default_data.results, 0, "Expected default results value");
+
+        assert_eq!(
+            default_data.paging,
+            Paging::default(),
+            "Expected default paging"
+        );
+
+        assert!(
+            default_data.response.is_empty(),
+            "Expected no response data in default"
+        );
+
+        assert_eq!(
+            default_data.get_statistics_comparison(&Teams::default()),
+            "Statistics are not available yet\n"
+        );
+
+        Ok(())
+    }
+}
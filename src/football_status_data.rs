@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Write};
+
+use chrono::DateTime;
+
+use crate::{football_ratelimit::RateLimit, StringType};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Account {
+    pub firstname: Option<StringType>,
+    pub lastname: Option<StringType>,
+    pub email: Option<StringType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Subscription {
+    pub plan: StringType,
+    pub end: Option<StringType>,
+    pub active: bool,
+}
+
+/// Requests made today and the daily limit of the plan
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Requests {
+    pub current: u32,
+    pub limit_day: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    pub account: Account,
+    pub subscription: Subscription,
+    pub requests: Requests,
+}
+
+/// The api answers with an empty list instead of the status on errors
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum StatusResponse {
+    Status(Status),
+    Empty(Vec<serde_json::Value>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FootballStatusErrors {
+    Empty(Vec<Option<serde_json::Value>>),
+    WithMessages(HashMap<String, String>),
+}
+
+/// Account, plan and daily usage of the api key, the `status` endpoint
+/// does not count against the quota
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootballStatusData {
+    pub get: StringType,
+    pub errors: FootballStatusErrors,
+    pub results: usize,
+    pub response: StatusResponse,
+}
+
+impl Default for FootballStatusData {
+    fn default() -> Self {
+        Self {
+            get: "".into(),
+            errors: FootballStatusErrors::Empty(Vec::new()),
+            results: 0,
+            response: StatusResponse::Empty(Vec::new()),
+        }
+    }
+}
+
+impl FootballStatusData {
+    #[must_use]
+    pub fn status(&self) -> Option<&Status> {
+        match &self.response {
+            StatusResponse::Status(status) => Some(status),
+            StatusResponse::Empty(_) => None,
+        }
+    }
+
+    /// Write out the plan and the requests used today and, from the rate
+    /// limit headers, this minute.
+    /// ```
+    /// use footballscore::football_status_data::FootballStatusData;
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/status.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballStatusData = serde_json::from_str(&buf)?;
+    ///
+    /// let buf = data.get_quota_information(None);
+    ///
+    /// assert!(buf.contains("Requests today: 12 of 100 (88 left)"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_quota_information(&self, rate_limit: Option<&RateLimit>) -> StringType {
+        let mut output = StringType::from("");
+
+        if let Some(status) = self.status() {
+            let subscription = &status.subscription;
+            let _ = write!(output, "Plan: {}", subscription.plan);
+            let end = subscription
+                .end
+                .as_ref()
+                .and_then(|end| DateTime::parse_from_rfc3339(end).ok());
+            match (subscription.active, end) {
+                (true, Some(end)) => {
+                    let _ = write!(output, ", active until {}", end.format("%Y-%m-%d"));
+                }
+                (true, None) => output.push_str(", active"),
+                (false, _) => output.push_str(", inactive"),
+            }
+            output.push('\n');
+
+            let requests = &status.requests;
+            let _ = writeln!(
+                output,
+                "Requests today: {} of {} ({} left)",
+                requests.current,
+                requests.limit_day,
+                requests.limit_day.saturating_sub(requests.current)
+            );
+
+            if let Some(RateLimit {
+                minute_limit: Some(limit),
+                minute_remaining: Some(remaining),
+                ..
+            }) = rate_limit
+            {
+                let _ = writeln!(output, "Requests this minute: {remaining} of {limit} left");
+            }
+            output.push_str("The daily quota starts over at 00:00 UTC\n");
+        } else if let FootballStatusErrors::WithMessages(error_messages) = &self.errors {
+            for field_name in &["access", "token", "requests"] {
+                if let Some(error) = error_messages.get(*field_name) {
+                    let _ = writeln!(output, "Error: {field_name} - {error}");
+                }
+            }
+        } else {
+            output.push_str("Your quota is unavailable\n");
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        football_ratelimit::RateLimit,
+        football_status_data::{FootballStatusData, Requests},
+        Error,
+    };
+
+    #[test]
+    fn test_football_status_data() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/status.json");
+        let data: FootballStatusData = serde_json::from_str(buf)?;
+
+        let status = data
+            .status()
+            .ok_or_else(|| Error::InvalidValue("status".into()))?;
+        assert_eq!(&status.subscription.plan, "Free");
+        assert_eq!(
+            status.requests,
+            Requests {
+                current: 12,
+                limit_day: 100
+            }
+        );
+
+        let now = Utc
+            .with_ymd_and_hms(2024, 10, 19, 18, 0, 0)
+            .single()
+            .unwrap_or_default();
+        let rate_limit = RateLimit {
+            requests_limit: Some(100),
+            requests_remaining: Some(87),
+            minute_limit: Some(10),
+            minute_remaining: Some(9),
+            updated_at: now,
+        };
+        let lines: Vec<_> = data
+            .get_quota_information(Some(&rate_limit))
+            .lines()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Plan: Free, active until 2025-04-10",
+                "Requests today: 12 of 100 (88 left)",
+                "Requests this minute: 9 of 10 left",
+                "The daily quota starts over at 00:00 UTC",
+            ]
+        );
+
+        let error = r#"{"get": "status", "parameters": [], "errors": {"token": "Error/Missing application key."}, "results": 0, "paging": {"current": 1, "total": 1}, "response": []}"#;
+        let data: FootballStatusData = serde_json::from_str(error)?;
+        assert_eq!(data.status(), None);
+        assert_eq!(
+            data.get_quota_information(None),
+            "Error: token - Error/Missing application key.\n"
+        );
+        assert_eq!(
+            FootballStatusData::default().get_quota_information(None),
+            "Your quota is unavailable\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+use crate::{
+    football_fixtures_data::{
+        FixtureStatus, FootballErrors, FootballFixturesData, Response, Timezone,
+    },
+    format_string, Error, StringType,
+};
+
+/// Status bar the output is written for
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusBarFormat {
+    /// JSON object with `text`, `tooltip` and `class`
+    Waybar,
+    /// `full_text`, `short_text` and `color` lines
+    I3blocks,
+    /// Text wrapped in `%{F}` colour tags
+    Polybar,
+}
+
+/// State of the match shown in the bar, used as waybar `class`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusBarClass {
+    Live,
+    Upcoming,
+    Finished,
+    Postponed,
+    Cancelled,
+    None,
+    Error,
+}
+
+impl StatusBarClass {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::Upcoming => "upcoming",
+            Self::Finished => "finished",
+            Self::Postponed => "postponed",
+            Self::Cancelled => "cancelled",
+            Self::None => "none",
+            Self::Error => "error",
+        }
+    }
+
+    /// Colour for the bars without css classes
+    #[must_use]
+    pub fn color(self) -> &'static str {
+        match self {
+            Self::Live => "#b5bd68",
+            Self::Upcoming => "#8abeb7",
+            Self::Finished | Self::None => "#c5c8c6",
+            Self::Postponed | Self::Cancelled => "#de935f",
+            Self::Error => "#aa5a5b",
+        }
+    }
+}
+
+/// A single match summarised for a status bar
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StatusBarOutput {
+    pub text: StringType,
+
+    #[serde(skip)]
+    pub short_text: StringType,
+
+    pub tooltip: StringType,
+    pub class: StatusBarClass,
+}
+
+impl StatusBarOutput {
+    /// Summarise the most relevant fixture: a live one, otherwise the next
+    /// one to kick off, otherwise the most recent result. The tooltip lists
+    /// every fixture.
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData,
+    ///     football_statusbar::{StatusBarFormat, StatusBarOutput},
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let output = StatusBarOutput::from_fixtures(&data, &"UTC".parse()?)?;
+    ///
+    /// assert_eq!(
+    ///     output.render(StatusBarFormat::Polybar)?,
+    ///     "%{F#b5bd68}Barcelona 0 - 1 Arsenal HT%{F-}\n"
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn from_fixtures(data: &FootballFixturesData, timezone: &Timezone) -> Result<Self, Error> {
+        let tooltip: StringType = data
+            .get_fixtures_information(None, timezone)?
+            .trim_end()
+            .into();
+
+        let mut fixtures: Vec<&Response> = data.response.iter().collect();
+        fixtures.sort_by_key(|response| response.fixture.timestamp);
+
+        let fixture = fixtures
+            .iter()
+            .find(|response| response.fixture.status.short.is_live())
+            .or_else(|| {
+                fixtures
+                    .iter()
+                    .find(|response| !response.fixture.status.short.is_finished())
+            })
+            .or_else(|| fixtures.last());
+
+        let Some(response) = fixture else {
+            let (text, class) = match &data.errors {
+                FootballErrors::WithMessages(_) => ("Error", StatusBarClass::Error),
+                FootballErrors::Empty(_) => ("No match", StatusBarClass::None),
+            };
+            return Ok(Self {
+                text: text.into(),
+                short_text: "-".into(),
+                tooltip,
+                class,
+            });
+        };
+
+        let status = &response.fixture.status;
+        let home = &response.teams.home.name;
+        let away = &response.teams.away.name;
+        let goals = format_string!(
+            "{} - {}",
+            response.goals.home.unwrap_or_default(),
+            response.goals.away.unwrap_or_default()
+        );
+
+        let (text, short_text, class) = if status.short.is_live() {
+            let minute = match status.elapsed {
+                Some(elapsed) if status.short != FixtureStatus::HalfTime => {
+                    format_string!("{elapsed}'")
+                }
+                _ => status.short.as_str().into(),
+            };
+            (
+                format_string!("{home} {goals} {away} {minute}"),
+                format_string!("{goals} {minute}"),
+                StatusBarClass::Live,
+            )
+        } else if status.short.is_finished() {
+            (
+                format_string!("{home} {goals} {away} ({})", status.short),
+                format_string!("{goals} ({})", status.short),
+                StatusBarClass::Finished,
+            )
+        } else if status.short.is_postponed() || status.short.is_cancelled() {
+            let class = if status.short.is_postponed() {
+                StatusBarClass::Postponed
+            } else {
+                StatusBarClass::Cancelled
+            };
+            (
+                format_string!("{home} vs {away} ({})", status.short),
+                format_string!("{}", status.short),
+                class,
+            )
+        } else {
+            let kickoff = response.kickoff(timezone)?;
+            (
+                format_string!("{home} vs {away} {}", kickoff.format("%b %d %I:%M %p")),
+                format_string!("{}", kickoff.format("%I:%M %p")),
+                StatusBarClass::Upcoming,
+            )
+        };
+
+        Ok(Self {
+            text,
+            short_text,
+            tooltip,
+            class,
+        })
+    }
+
+    /// Write out the summary in the native structure of the bar
+    /// # Errors
+    ///
+    /// Will return error if serialization fails
+    pub fn render(&self, format: StatusBarFormat) -> Result<StringType, Error> {
+        let mut output = StringType::from("");
+
+        match format {
+            StatusBarFormat::Waybar => {
+                output.push_str(&serde_json::to_string(self)?);
+                output.push('\n');
+            }
+            StatusBarFormat::I3blocks => {
+                writeln!(output, "{}", &self.text)?;
+                writeln!(output, "{}", &self.short_text)?;
+                writeln!(output, "{}", self.class.color())?;
+            }
+            StatusBarFormat::Polybar => {
+                writeln!(output, "%{{F{}}}{}%{{F-}}", self.class.color(), &self.text)?;
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{FixtureStatus, FootballFixturesData, Timezone},
+        football_statusbar::{StatusBarClass, StatusBarFormat, StatusBarOutput},
+        Error,
+    };
+
+    const UTC: Timezone = Timezone::Named(chrono_tz::UTC);
+
+    #[test]
+    fn test_statusbar_output() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+
+        let output = StatusBarOutput::from_fixtures(&data, &UTC)?;
+        assert_eq!(output.class, StatusBarClass::Live);
+
+        let waybar = output.render(StatusBarFormat::Waybar)?;
+        let value: serde_json::Value = serde_json::from_str(&waybar)?;
+        assert_eq!(value["text"], "Barcelona 0 - 1 Arsenal HT");
+        assert_eq!(value["class"], "live");
+        assert!(value["tooltip"]
+            .as_str()
+            .is_some_and(|tooltip| tooltip.starts_with("Match: Barcelona 0 vs 1 Arsenal\n")));
+
+        data.response[0].fixture.status.short = FixtureStatus::NotStarted;
+        let output = StatusBarOutput::from_fixtures(&data, &UTC)?;
+        assert_eq!(
+            output.render(StatusBarFormat::I3blocks)?,
+            "Barcelona vs Arsenal Feb 06 02:00 PM\n02:00 PM\n#8abeb7\n"
+        );
+
+        // the most recent result once every match is over
+        let buf = include_str!("../tests/resource/results.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+        let output = StatusBarOutput::from_fixtures(&data, &UTC)?;
+        assert_eq!(
+            output.render(StatusBarFormat::Polybar)?,
+            "%{F#c5c8c6}Arsenal 3 - 5 Barcelona (FT)%{F-}\n"
+        );
+
+        let output = StatusBarOutput::from_fixtures(&FootballFixturesData::default(), &UTC)?;
+        assert_eq!(output.class, StatusBarClass::None);
+        assert_eq!(output.tooltip, "Match: no live event");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use crate::{
+    football_fixtures_data::{FootballErrors, FootballFixturesData, Response, Timezone},
+    football_output::ErrorOutput,
+    football_teams_data::{FootballTeamsData, FootballTeamsErrors},
+    format_string, Error, StringType,
+};
+
+const FIXTURE_HEADERS: [&str; 9] = [
+    "date",
+    "competition",
+    "round",
+    "home",
+    "away",
+    "score",
+    "ht score",
+    "status",
+    "venue",
+];
+
+const TEAM_HEADERS: [&str; 8] = [
+    "id", "name", "code", "country", "founded", "venue", "city", "capacity",
+];
+
+/// Kick-off format of the `date` column
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Score as `home-away`, empty until the match kicks off
+fn score(home: Option<usize>, away: Option<usize>) -> StringType {
+    match (home, away) {
+        (Some(home), Some(away)) => format_string!("{home}-{away}"),
+        _ => StringType::from(""),
+    }
+}
+
+/// Quote a CSV field when it contains a separator, a quote or a line break
+/// (RFC 4180)
+fn escape_csv(field: &str) -> StringType {
+    if !field.contains([',', '"', '\r', '\n']) {
+        return field.into();
+    }
+
+    let mut escaped = StringType::from("\"");
+    for c in field.chars() {
+        if c == '"' {
+            escaped.push('"');
+        }
+        escaped.push(c);
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// Escape the pipes of a Markdown table cell, it has to stay on one line
+fn escape_markdown(cell: &str) -> StringType {
+    let mut escaped = StringType::from("");
+
+    for c in cell.chars() {
+        match c {
+            '|' => escaped.push_str("\\|"),
+            '\r' | '\n' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The api errors as a single error, a table has nowhere to put them
+fn api_error(error_messages: &HashMap<String, String>) -> Error {
+    let mut message = StringType::from("\nERROR: api request failed");
+
+    for error in ErrorOutput::from_messages(error_messages) {
+        message.push_str(&format_string!("\n{}: {}", error.field, error.message));
+    }
+    message.push('\n');
+
+    Error::InvalidValue(message)
+}
+
+/// Rows written out by `--output csv` and `--output markdown`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    pub headers: Vec<StringType>,
+    pub rows: Vec<Vec<StringType>>,
+}
+
+impl Table {
+    /// One row per fixture ordered by kick-off, keeping at most `limit` of
+    /// them: date, competition, round, home, away, score, half-time score,
+    /// status and venue.
+    /// ```
+    /// use footballscore::{football_fixtures_data::FootballFixturesData, football_table::Table};
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let table = Table::from_fixtures(&data, None, &"UTC".parse()?)?;
+    ///
+    /// assert_eq!(table.rows[0][3], "Barcelona");
+    /// assert_eq!(table.rows[0][5], "0-1");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the api rejected the request or the date of a
+    /// fixture is malformed
+    pub fn from_fixtures(
+        data: &FootballFixturesData,
+        limit: Option<usize>,
+        timezone: &Timezone,
+    ) -> Result<Self, Error> {
+        if let FootballErrors::WithMessages(error_messages) = &data.errors {
+            return Err(api_error(error_messages));
+        }
+
+        let mut fixtures: Vec<&Response> = data.response.iter().collect();
+        fixtures.sort_by_key(|response| response.fixture.timestamp);
+
+        if let Some(limit) = limit {
+            fixtures.truncate(limit);
+        }
+
+        let mut rows = Vec::with_capacity(fixtures.len());
+
+        for response in fixtures {
+            rows.push(vec![
+                format_string!("{}", response.kickoff(timezone)?.format(DATE_FORMAT)),
+                response.league.name.clone(),
+                response.league.round.clone(),
+                response.teams.home.name.clone(),
+                response.teams.away.name.clone(),
+                score(response.goals.home, response.goals.away),
+                score(response.score.halftime.home, response.score.halftime.away),
+                response.fixture.status.short.as_str().into(),
+                response.fixture.venue.name.clone(),
+            ]);
+        }
+
+        Ok(Self {
+            headers: FIXTURE_HEADERS
+                .iter()
+                .map(|&header| header.into())
+                .collect(),
+            rows,
+        })
+    }
+
+    /// One row per club found by `--club-name`: id, name, code, country,
+    /// founded, venue, city and capacity
+    /// # Errors
+    ///
+    /// Will return error if the api rejected the request
+    pub fn from_teams(data: &FootballTeamsData) -> Result<Self, Error> {
+        if let FootballTeamsErrors::WithMessages(error_messages) = &data.errors {
+            return Err(api_error(error_messages));
+        }
+
+        let rows = data
+            .response
+            .iter()
+            .map(|response| {
+                let team = &response.team;
+                let venue = &response.venue;
+                vec![
+                    team.id.map(|id| format_string!("{id}")).unwrap_or_default(),
+                    team.name.clone().unwrap_or_default(),
+                    team.code.clone().unwrap_or_default(),
+                    team.country.clone().unwrap_or_default(),
+                    team.founded
+                        .map(|founded| format_string!("{founded}"))
+                        .unwrap_or_default(),
+                    venue.name.clone().unwrap_or_default(),
+                    venue.city.clone().unwrap_or_default(),
+                    venue
+                        .capacity
+                        .map(|capacity| format_string!("{capacity}"))
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect();
+
+        Ok(Self {
+            headers: TEAM_HEADERS.iter().map(|&header| header.into()).collect(),
+            rows,
+        })
+    }
+
+    /// Write out the table as CSV with a header line, fields are quoted as
+    /// described in RFC 4180
+    #[must_use]
+    pub fn to_csv(&self) -> StringType {
+        let mut output = StringType::from("");
+
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            for (index, field) in row.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                output.push_str(&escape_csv(field));
+            }
+            output.push_str("\r\n");
+        }
+
+        output
+    }
+
+    /// Write out the table as a GitHub flavoured Markdown table
+    #[must_use]
+    pub fn to_markdown(&self) -> StringType {
+        let mut output = StringType::from("");
+
+        let mut write_row = |row: &[StringType]| {
+            output.push('|');
+            for cell in row {
+                output.push(' ');
+                output.push_str(&escape_markdown(cell));
+                output.push_str(" |");
+            }
+            output.push('\n');
+        };
+
+        write_row(&self.headers);
+        write_row(&vec!["---".into(); self.headers.len()]);
+        for row in &self.rows {
+            write_row(row);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{FootballFixturesData, Timezone},
+        football_table::{escape_csv, Table},
+        football_teams_data::FootballTeamsData,
+        Error,
+    };
+
+    #[test]
+    fn test_fixtures_table() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+        data.response[1].fixture.venue.name = "Camp Nou, \"Barça\"".into();
+        let timezone = Timezone::Named(chrono_tz::Europe::Madrid);
+
+        let table = Table::from_fixtures(&data, Some(2), &timezone)?;
+        assert_eq!(
+            table.to_csv(),
+            "date,competition,round,home,away,score,ht score,status,venue\r\n\
+             2010-03-31 20:45,UEFA Champions League,Quarter-finals,Arsenal,Barcelona,2-2,0-0,FT,Emirates Stadium\r\n\
+             2010-04-06 20:45,UEFA Champions League,Quarter-finals,Barcelona,Arsenal,4-1,1-1,FT,\"Camp Nou, \"\"Barça\"\"\"\r\n"
+        );
+
+        let markdown = table.to_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "| --- | --- | --- | --- | --- | --- | --- | --- | --- |"
+        );
+        assert_eq!(
+            lines[2],
+            "| 2010-03-31 20:45 | UEFA Champions League | Quarter-finals | Arsenal | Barcelona | 2-2 | 0-0 | FT | Emirates Stadium |"
+        );
+
+        let buf = r#"{
+            "get": "fixtures",
+            "parameters": {"team": "529"},
+            "errors": {"token": "Missing application key."},
+            "results": 0,
+            "paging": {"current": 1, "total": 1},
+            "response": []
+        }"#;
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+        assert!(Table::from_fixtures(&data, None, &timezone).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_teams_table() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/teams.json");
+        let data: FootballTeamsData = serde_json::from_str(buf)?;
+
+        let table = Table::from_teams(&data)?;
+        assert_eq!(
+            table.to_csv(),
+            "id,name,code,country,founded,venue,city,capacity\r\n\
+             529,Barcelona,BAR,Spain,1899,Estadi Olímpic Lluís Companys,Barcelona,55926\r\n"
+        );
+
+        assert_eq!(escape_csv("Brighton & Hove"), "Brighton & Hove");
+        assert_eq!(escape_csv("a\nb"), "\"a\nb\"");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use chrono::format::{Item, StrftimeItems};
+use std::{fmt::Write, str::FromStr};
+
+use crate::{
+    football_fixtures_data::{FootballFixturesData, Response, Timezone},
+    format_string, Error, StringType,
+};
+
+/// Kick-off format used by a bare `{kickoff}`
+const DEFAULT_KICKOFF_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Value a placeholder is replaced with
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Placeholder {
+    Id,
+    Home,
+    Away,
+    HomeGoals,
+    AwayGoals,
+    Status,
+    StatusLong,
+    Elapsed,
+    Kickoff(StringType),
+    League,
+    Country,
+    Round,
+    Season,
+    Venue,
+    City,
+}
+
+impl Placeholder {
+    fn parse(placeholder: &str) -> Result<Self, Error> {
+        let (name, format) = match placeholder.split_once(':') {
+            Some((name, format)) => (name, Some(format)),
+            None => (placeholder, None),
+        };
+
+        let placeholder = match (name, format) {
+            ("id", None) => Self::Id,
+            ("home", None) => Self::Home,
+            ("away", None) => Self::Away,
+            ("home_goals", None) => Self::HomeGoals,
+            ("away_goals", None) => Self::AwayGoals,
+            ("status", None) => Self::Status,
+            ("status_long", None) => Self::StatusLong,
+            ("elapsed", None) => Self::Elapsed,
+            ("kickoff", format) => {
+                let format = format.unwrap_or(DEFAULT_KICKOFF_FORMAT);
+                if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                    return Err(Error::InvalidInputError(format_string!(
+                        "\nERROR: invalid kick-off format `{format}` in template\n"
+                    )));
+                }
+                Self::Kickoff(format.into())
+            }
+            ("league", None) => Self::League,
+            ("country", None) => Self::Country,
+            ("round", None) => Self::Round,
+            ("season", None) => Self::Season,
+            ("venue", None) => Self::Venue,
+            ("city", None) => Self::City,
+            _ => {
+                return Err(Error::InvalidInputError(format_string!(
+                    "\nERROR: unknown placeholder `{{{placeholder}}}` in template\n"
+                )))
+            }
+        };
+
+        Ok(placeholder)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(StringType),
+    Placeholder(Placeholder),
+}
+
+/// Format string a fixture is written out with, e.g.
+/// `{kickoff:%H:%M} {home} {home_goals}-{away_goals} {away} ({status})`.
+///
+/// Available placeholders are `{id}`, `{home}`, `{away}`, `{home_goals}`,
+/// `{away_goals}`, `{status}`, `{status_long}`, `{elapsed}`, `{kickoff}`,
+/// `{league}`, `{country}`, `{round}`, `{season}`, `{venue}` and `{city}`.
+/// `{kickoff:FORMAT}` takes a `strftime` format. Goals and elapsed minutes
+/// are empty until the match kicks off. Use `{{` and `}}` for literal braces,
+/// `\n` and `\t` for a newline and a tab.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureTemplate {
+    segments: Vec<Segment>,
+}
+
+impl FromStr for FixtureTemplate {
+    type Err = Error;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let mut segments = Vec::new();
+        let mut literal = StringType::from("");
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    literal.push(c);
+                    chars.next();
+                }
+                ('\\', Some('n')) => {
+                    literal.push('\n');
+                    chars.next();
+                }
+                ('\\', Some('t')) => {
+                    literal.push('\t');
+                    chars.next();
+                }
+                ('{', _) => {
+                    let mut placeholder = StringType::from("");
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => {
+                                return Err(Error::InvalidInputError(format_string!(
+                                    "\nERROR: unclosed `{{` in template\n"
+                                )))
+                            }
+                        }
+                    }
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(literal.clone()));
+                        literal.clear();
+                    }
+                    segments.push(Segment::Placeholder(Placeholder::parse(&placeholder)?));
+                }
+                ('}', _) => {
+                    return Err(Error::InvalidInputError(format_string!(
+                        "\nERROR: unmatched `}}` in template, use `}}}}` for a literal brace\n"
+                    )))
+                }
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self { segments })
+    }
+}
+
+impl FixtureTemplate {
+    /// Write out a single fixture
+    /// # Errors
+    ///
+    /// Will return error if the date of the fixture is malformed
+    pub fn render(&self, response: &Response, timezone: &Timezone) -> Result<StringType, Error> {
+        let mut output = StringType::from("");
+
+        for segment in &self.segments {
+            let placeholder = match segment {
+                Segment::Literal(literal) => {
+                    output.push_str(literal);
+                    continue;
+                }
+                Segment::Placeholder(placeholder) => placeholder,
+            };
+
+            match placeholder {
+                Placeholder::Id => write!(output, "{}", response.fixture.id)?,
+                Placeholder::Home => output.push_str(&response.teams.home.name),
+                Placeholder::Away => output.push_str(&response.teams.away.name),
+                Placeholder::HomeGoals => {
+                    if let Some(goals) = response.goals.home {
+                        write!(output, "{goals}")?;
+                    }
+                }
+                Placeholder::AwayGoals => {
+                    if let Some(goals) = response.goals.away {
+                        write!(output, "{goals}")?;
+                    }
+                }
+                Placeholder::Status => write!(output, "{}", response.fixture.status.short)?,
+                Placeholder::StatusLong => output.push_str(&response.fixture.status.long),
+                Placeholder::Elapsed => {
+                    if let Some(elapsed) = response.fixture.status.elapsed {
+                        write!(output, "{elapsed}")?;
+                    }
+                }
+                Placeholder::Kickoff(format) => {
+                    write!(output, "{}", response.kickoff(timezone)?.format(format))?;
+                }
+                Placeholder::League => output.push_str(&response.league.name),
+                Placeholder::Country => output.push_str(&response.league.country),
+                Placeholder::Round => output.push_str(&response.league.round),
+                Placeholder::Season => write!(output, "{}", response.league.season)?,
+                Placeholder::Venue => output.push_str(&response.fixture.venue.name),
+                Placeholder::City => output.push_str(&response.fixture.venue.city),
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Write out every fixture ordered by kick-off, one per line, keeping at
+    /// most `limit` of them. Without fixtures the api errors or
+    /// `Match: no live event` are written out instead.
+    /// ```
+    /// use footballscore::{
+    ///     football_fixtures_data::FootballFixturesData, football_template::FixtureTemplate,
+    /// };
+    /// # use anyhow::Error;
+    /// # use std::io::{stdout, Write, Read};
+    /// # use std::fs::File;
+    /// # fn main() -> Result<(), Error> {
+    /// # let mut buf = String::new();
+    /// # let mut f = File::open("tests/resource/fixtures.json")?;
+    /// # f.read_to_string(&mut buf)?;
+    /// let data: FootballFixturesData = serde_json::from_str(&buf)?;
+    ///
+    /// let template: FixtureTemplate = "{home} {home_goals}-{away_goals} {away} ({status})".parse()?;
+    /// let buf = template.render_fixtures(&data, None, &"UTC".parse()?)?;
+    ///
+    /// assert_eq!(buf, "Barcelona 0-1 Arsenal (HT)\n");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Will return error if the date of a fixture is malformed
+    pub fn render_fixtures(
+        &self,
+        data: &FootballFixturesData,
+        limit: Option<usize>,
+        timezone: &Timezone,
+    ) -> Result<StringType, Error> {
+        let mut fixtures: Vec<&Response> = data.response.iter().collect();
+        fixtures.sort_by_key(|response| response.fixture.timestamp);
+
+        if let Some(limit) = limit {
+            fixtures.truncate(limit);
+        }
+
+        if fixtures.is_empty() {
+            return data.get_fixtures_information(None, timezone);
+        }
+
+        let mut output = StringType::from("");
+
+        for response in fixtures {
+            output.push_str(&self.render(response, timezone)?);
+            if !output.ends_with('\n') {
+                output.push('\n');
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        football_fixtures_data::{FootballFixturesData, Timezone},
+        football_template::FixtureTemplate,
+        Error,
+    };
+
+    #[test]
+    fn test_fixture_template() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/headtohead.json");
+        let data: FootballFixturesData = serde_json::from_str(buf)?;
+        let timezone = Timezone::Named(chrono_tz::Europe::Madrid);
+
+        let template: FixtureTemplate =
+            r"{kickoff:%d/%m %H:%M}\t{home} {home_goals}-{away_goals} {away} [{league}, {venue}]"
+                .parse()?;
+        let buf = template.render_fixtures(&data, Some(2), &timezone)?;
+        assert_eq!(
+            buf,
+            "31/03 20:45\tArsenal 2-2 Barcelona [UEFA Champions League, Emirates Stadium]\n\
+             06/04 20:45\tBarcelona 4-1 Arsenal [UEFA Champions League, Camp Nou]\n"
+        );
+
+        let template: FixtureTemplate = "{{{status}}} {kickoff} {elapsed}'".parse()?;
+        let buf = template.render(&data.response[0], &timezone)?;
+        assert_eq!(buf, "{FT} 2023-08-09 04:30 90'");
+
+        assert!("{home".parse::<FixtureTemplate>().is_err());
+        assert!("home}".parse::<FixtureTemplate>().is_err());
+        assert!("{score}".parse::<FixtureTemplate>().is_err());
+        assert!("{home:%H}".parse::<FixtureTemplate>().is_err());
+        assert!("{kickoff:%Q}".parse::<FixtureTemplate>().is_err());
+
+        let buf = template.render_fixtures(&FootballFixturesData::default(), None, &timezone)?;
+        assert_eq!(buf, "Match: no live event");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use std::{collections::HashSet, time::Duration};
+
+use crate::football_fixtures_data::FootballFixturesData;
+
+/// Seconds between two polls while a match is live, unless `--interval` is given
+pub const DEFAULT_WATCH_INTERVAL: u64 = 60;
+
+/// Time between two polls while no match is live, keeps the daily quota of
+/// the api for the matches themselves
+pub const IDLE_WATCH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// What to do after a poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchState {
+    /// Poll again after the given delay
+    Continue(Duration),
+    /// The watched matches are over
+    Finished,
+}
+
+/// Decides how long to wait between two polls of `--watch` and when to stop.
+///
+/// Polls every `interval` while a match is live, backs off to
+/// `IDLE_WATCH_INTERVAL` otherwise, waking up in time for the next kick-off.
+/// Watching stops once every match seen live has finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureWatch {
+    interval: Duration,
+    live_fixtures: HashSet<u32>,
+}
+
+impl FixtureWatch {
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            live_fixtures: HashSet::new(),
+        }
+    }
+
+    /// Delay before the next poll when the poll failed
+    #[must_use]
+    pub fn retry_interval(&self) -> Duration {
+        self.interval
+            .max(IDLE_WATCH_INTERVAL.min(self.interval * 5))
+    }
+
+    /// Record the fixtures of the latest poll made at `now`, a response the
+    /// api answered with errors is retried like a failed poll
+    pub fn update(&mut self, data: &FootballFixturesData, now: DateTime<Utc>) -> WatchState {
+        if data.get_api_errors().is_some() {
+            return WatchState::Continue(self.retry_interval());
+        }
+
+        let live: HashSet<u32> = data
+            .response
+            .iter()
+            .filter(|response| response.fixture.status.short.is_live())
+            .map(|response| response.fixture.id)
+            .collect();
+
+        if !live.is_empty() {
+            self.live_fixtures.extend(&live);
+            return WatchState::Continue(self.interval);
+        }
+
+        // a live match drops out of `--live` once it is over
+        if !self.live_fixtures.is_empty() {
+            return WatchState::Finished;
+        }
+
+        let now = u32::try_from(now.timestamp()).unwrap_or(u32::MAX);
+        let next_kickoff = data
+            .response
+            .iter()
+            .filter(|response| !response.fixture.status.short.is_finished())
+            .map(|response| response.fixture.timestamp)
+            .filter(|&timestamp| timestamp > now)
+            .min();
+
+        let delay = next_kickoff.map_or(IDLE_WATCH_INTERVAL, |kickoff| {
+            IDLE_WATCH_INTERVAL.min(Duration::from_secs(u64::from(kickoff - now)))
+        });
+
+        WatchState::Continue(delay.max(self.interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use std::time::Duration;
+
+    use crate::{
+        football_fixtures_data::{FixtureStatus, FootballErrors, FootballFixturesData},
+        football_watch::{FixtureWatch, WatchState, IDLE_WATCH_INTERVAL},
+        Error,
+    };
+
+    #[test]
+    fn test_fixture_watch() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let mut data: FootballFixturesData = serde_json::from_str(buf)?;
+        let kickoff = i64::from(data.response[0].fixture.timestamp);
+        let interval = Duration::from_secs(30);
+        let mut watch = FixtureWatch::new(interval);
+
+        // back off until close to kick-off
+        data.response[0].fixture.status.short = FixtureStatus::NotStarted;
+        let now = DateTime::from_timestamp(kickoff - 3600, 0).unwrap_or_default();
+        assert_eq!(
+            watch.update(&data, now),
+            WatchState::Continue(IDLE_WATCH_INTERVAL)
+        );
+        let now = DateTime::from_timestamp(kickoff - 120, 0).unwrap_or_default();
+        assert_eq!(
+            watch.update(&data, now),
+            WatchState::Continue(Duration::from_secs(120))
+        );
+        let now = DateTime::from_timestamp(kickoff - 10, 0).unwrap_or_default();
+        assert_eq!(watch.update(&data, now), WatchState::Continue(interval));
+
+        // poll at the interval during the match
+        data.response[0].fixture.status.short = FixtureStatus::HalfTime;
+        let now = DateTime::from_timestamp(kickoff + 2700, 0).unwrap_or_default();
+        assert_eq!(watch.update(&data, now), WatchState::Continue(interval));
+
+        // a rate limit mid-match answers with no fixtures, it does not end it
+        let errored = FootballFixturesData {
+            errors: FootballErrors::WithMessages(
+                [("rateLimit".into(), "Too many requests".into())].into(),
+            ),
+            ..FootballFixturesData::default()
+        };
+        assert_eq!(
+            watch.update(&errored, now),
+            WatchState::Continue(Duration::from_secs(150))
+        );
+        assert_eq!(watch.update(&data, now), WatchState::Continue(interval));
+
+        data.response[0].fixture.status.short = FixtureStatus::FullTime;
+        assert_eq!(watch.update(&data, now), WatchState::Finished);
+        assert_eq!(
+            watch.update(&FootballFixturesData::default(), now),
+            WatchState::Finished
+        );
+
+        // nothing scheduled
+        let mut watch = FixtureWatch::new(interval);
+        assert_eq!(
+            watch.update(&FootballFixturesData::default(), now),
+            WatchState::Continue(IDLE_WATCH_INTERVAL)
+        );
+        assert_eq!(watch.retry_interval(), Duration::from_secs(150));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{header::CONTENT_TYPE, Client, Response, Url};
+use serde::Serialize;
+use sha2::Sha256;
+use std::{fmt::Write, path::PathBuf, time::Duration};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, time::sleep};
+
+use crate::{football_changes::FixtureChange, format_string, Error, StringType};
+
+/// Header carrying `sha256=<hex>`, the HMAC-SHA256 of the body keyed with
+/// the webhook secret
+pub const SIGNATURE_HEADER: &str = "x-footballscore-signature";
+
+/// Name of the dead-letter file in the state directory
+pub const DEAD_LETTER_FILE: &str = "webhook-dead-letter.jsonl";
+
+/// Deliveries tried before the payload goes to the dead-letter file
+const DEFAULT_MAX_ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry, doubled for each further one
+const DEFAULT_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Time to establish a connection to a receiver
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time for a whole delivery, a stalled receiver counts as a failed attempt
+/// rather than holding up `--watch`
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Line of the dead-letter file, one JSON object per failed delivery
+#[derive(Serialize)]
+struct DeadLetter<'a> {
+    url: &'a str,
+    failed_at: StringType,
+    attempts: u32,
+    error: StringType,
+    payload: &'a FixtureChange,
+}
+
+/// POSTs every `FixtureChange` as JSON to the configured urls.
+///
+/// The payload is the serialized `FixtureChange`: `type` (`kick-off`,
+/// `goal`, `red-card`, `half-time` or `full-time`), `fixture_id`, `home`,
+/// `away`, `home_goals`, `away_goals`, `minute`, plus `team` for goals and
+/// `team` and `player` for red cards. A delivery that still fails after the
+/// retries is appended to the dead-letter file instead.
+#[derive(Debug, Clone)]
+pub struct WebhookSink {
+    client: Client,
+    urls: Vec<Url>,
+    secret: Option<StringType>,
+    max_attempts: u32,
+    backoff: Duration,
+    dead_letter_path: PathBuf,
+}
+
+impl WebhookSink {
+    #[must_use]
+    pub fn new(urls: Vec<Url>, dead_letter_path: impl Into<PathBuf>) -> Self {
+        Self {
+            client: Self::client(DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT),
+            urls,
+            secret: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            backoff: DEFAULT_BACKOFF,
+            dead_letter_path: dead_letter_path.into(),
+        }
+    }
+
+    fn client(connect_timeout: Duration, timeout: Duration) -> Client {
+        Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(timeout)
+            .build()
+            .unwrap_or_default()
+    }
+
+    /// Give up connecting to a receiver after `connect_timeout` and on a
+    /// delivery attempt after `timeout`
+    #[must_use]
+    pub fn with_timeout(mut self, connect_timeout: Duration, timeout: Duration) -> Self {
+        self.client = Self::client(connect_timeout, timeout);
+        self
+    }
+
+    /// Sign the payloads with `secret`, see `SIGNATURE_HEADER`
+    #[must_use]
+    pub fn with_secret(mut self, secret: &str) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Try each delivery `max_attempts` times, waiting `backoff` before the
+    /// first retry and twice as long before each further one
+    #[must_use]
+    pub fn with_retry(mut self, max_attempts: u32, backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.backoff = backoff;
+        self
+    }
+
+    /// Signature of `body` as sent in `SIGNATURE_HEADER`
+    /// ```
+    /// use footballscore::football_webhook::WebhookSink;
+    ///
+    /// assert_eq!(
+    ///     WebhookSink::sign("secret", b"{}"),
+    ///     "sha256=77325902caca812dc259733aacd046b73817372c777b8d95b402647474516e13"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn sign(secret: &str, body: &[u8]) -> StringType {
+        let mut signature = StringType::from("sha256=");
+
+        // HMAC takes keys of any length
+        if let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) {
+            mac.update(body);
+            for byte in mac.finalize().into_bytes() {
+                let _ = write!(signature, "{byte:02x}");
+            }
+        }
+
+        signature
+    }
+
+    /// Deliver every change to every url, failed deliveries are written to
+    /// the dead-letter file
+    /// # Errors
+    ///
+    /// Will return error if a payload cannot be serialized or the
+    /// dead-letter file cannot be written
+    pub async fn send(&self, changes: &[FixtureChange]) -> Result<(), Error> {
+        for change in changes {
+            let body = serde_json::to_vec(change)?;
+
+            for url in &self.urls {
+                if let Err(error) = self.deliver(url, &body).await {
+                    self.write_dead_letter(url, &error, change).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn deliver(&self, url: &Url, body: &[u8]) -> Result<(), StringType> {
+        let mut error = StringType::from("");
+
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                sleep(self.backoff * 2u32.saturating_pow(attempt - 1)).await;
+            }
+
+            let mut request = self
+                .client
+                .post(url.clone())
+                .header(CONTENT_TYPE, "application/json")
+                .body(body.to_vec());
+            if let Some(secret) = &self.secret {
+                request = request.header(SIGNATURE_HEADER, Self::sign(secret, body).as_str());
+            }
+
+            match request.send().await.and_then(Response::error_for_status) {
+                Ok(_) => return Ok(()),
+                Err(e) if e.is_timeout() => error = format_string!("timed out: {e}"),
+                Err(e) => error = format_string!("{e}"),
+            }
+        }
+
+        Err(error)
+    }
+
+    async fn write_dead_letter(
+        &self,
+        url: &Url,
+        error: &str,
+        change: &FixtureChange,
+    ) -> Result<(), Error> {
+        let dead_letter = DeadLetter {
+            url: url.as_str(),
+            failed_at: Utc::now().to_rfc3339().into(),
+            attempts: self.max_attempts,
+            error: error.into(),
+            payload: change,
+        };
+        let mut line = serde_json::to_vec(&dead_letter)?;
+        line.push(b'\n');
+
+        if let Some(dir) = self.dead_letter_path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.dead_letter_path)
+            .await?;
+        file.write_all(&line).await?;
+        file.flush().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use parking_lot::Mutex;
+    use std::{sync::Arc, time::Duration};
+    use tempfile::tempdir;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use crate::{
+        football_changes::detect_changes,
+        football_fixtures_data::FootballFixturesData,
+        football_webhook::{WebhookSink, DEAD_LETTER_FILE, SIGNATURE_HEADER},
+        Error,
+    };
+
+    /// Headers and body of a request received by the listener
+    type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Minimal HTTP receiver answering the requests with `statuses` in turn
+    async fn spawn_receiver(statuses: Vec<u16>) -> Result<(String, Received), Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/hook", listener.local_addr()?);
+        let received = Received::default();
+        let requests = received.clone();
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 1024];
+                let (head, body) = loop {
+                    let n = stream.read(&mut chunk).await.unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    let request = String::from_utf8_lossy(&buf).to_string();
+                    if let Some((head, body)) = request.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|line| {
+                                line.to_lowercase()
+                                    .strip_prefix("content-length: ")
+                                    .map(|length| length.parse().unwrap())
+                            })
+                            .unwrap_or_default();
+                        if body.len() >= length {
+                            break (head.to_lowercase(), body.to_string());
+                        }
+                    }
+                };
+                requests.lock().push((head, body));
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        Ok((url, received))
+    }
+
+    #[tokio::test]
+    async fn test_webhook_sink() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let previous: FootballFixturesData = serde_json::from_str(buf)?;
+        let mut current = previous.clone();
+        current.response[0].goals.home = Some(1);
+        let changes = detect_changes(&previous, &current);
+
+        let (url, received) = spawn_receiver(vec![503, 200]).await?;
+        let state_dir = tempdir()?;
+        let dead_letter_path = state_dir.path().join(DEAD_LETTER_FILE);
+
+        let sink = WebhookSink::new(vec![url.parse()?], &dead_letter_path)
+            .with_secret("secret")
+            .with_retry(3, Duration::from_millis(10));
+        sink.send(&changes).await?;
+
+        // delivered on the retry, signed
+        let received = received.lock().clone();
+        assert_eq!(received.len(), 2);
+        let (head, body) = &received[1];
+        assert!(head.starts_with("post /hook http/1.1"));
+        let signature = WebhookSink::sign("secret", body.as_bytes());
+        assert!(head.contains(&format!("{SIGNATURE_HEADER}: {signature}")));
+
+        let payload: serde_json::Value = serde_json::from_str(body)?;
+        assert_eq!(payload["type"], "goal");
+        assert_eq!(payload["fixture_id"], 239625);
+        assert_eq!(payload["team"], "Barcelona");
+        assert_eq!(payload["home"], "Barcelona");
+        assert_eq!(payload["home_goals"], 1);
+        assert_eq!(payload["away_goals"], 1);
+        assert_eq!(payload["minute"], 45);
+        assert!(!dead_letter_path.exists());
+
+        // every attempt fails, the delivery ends up in the dead-letter file
+        let (url, received) = spawn_receiver(vec![500, 500]).await?;
+        let sink = WebhookSink::new(vec![url.parse()?], &dead_letter_path)
+            .with_retry(2, Duration::from_millis(10));
+        sink.send(&changes).await?;
+
+        assert_eq!(received.lock().len(), 2);
+        assert!(!received.lock()[0].0.contains(SIGNATURE_HEADER));
+        let dead_letter = std::fs::read_to_string(&dead_letter_path)?;
+        assert_eq!(dead_letter.lines().count(), 1);
+        let dead_letter: serde_json::Value = serde_json::from_str(&dead_letter)?;
+        assert_eq!(dead_letter["url"], url.as_str());
+        assert_eq!(dead_letter["attempts"], 2);
+        assert_eq!(dead_letter["payload"]["type"], "goal");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_webhook_sink_timeout() -> Result<(), Error> {
+        let buf = include_str!("../tests/resource/fixtures.json");
+        let previous: FootballFixturesData = serde_json::from_str(buf)?;
+        let mut current = previous.clone();
+        current.response[0].goals.away = Some(2);
+        let changes = detect_changes(&previous, &current);
+
+        // accepts the connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/hook", listener.local_addr()?);
+        tokio::spawn(async move {
+            let mut streams = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                streams.push(stream);
+            }
+        });
+
+        let state_dir = tempdir()?;
+        let dead_letter_path = state_dir.path().join(DEAD_LETTER_FILE);
+        let sink = WebhookSink::new(vec![url.parse()?], &dead_letter_path)
+            .with_timeout(Duration::from_millis(50), Duration::from_millis(50))
+            .with_retry(2, Duration::from_millis(10));
+
+        tokio::time::timeout(Duration::from_secs(5), sink.send(&changes))
+            .await
+            .expect("a stalled receiver must not block the delivery")?;
+
+        let dead_letter = std::fs::read_to_string(&dead_letter_path)?;
+        let dead_letter: serde_json::Value = serde_json::from_str(&dead_letter)?;
+        assert_eq!(dead_letter["attempts"], 2);
+        assert!(dead_letter["error"]
+            .as_str()
+            .is_some_and(|error| error.contains("timed out")));
+
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@
 //!
 //! USAGE:
 //! footballscore [OPTIONS]
+//! footballscore [OPTIONS] quota
+//! footballscore [OPTIONS] serve [--bind <bind>] [--cache-ttl <cache-ttl>] [--interval <interval>]
 //!
 //! FLAGS:
 //! -h, --help      Prints help information
@@ -21,8 +23,38 @@
 //! OPTIONS:
 //! -k, --api-key <api-key>             Api key (optional but either this or API_KEY environment variable must exist)
 //!     --next-match <next-match>       Show next match (optional), but it must be `1`
+//!     --last-match <last-match>       Show the last N finished matches (optional), cannot be used with `--next-match`
+//!     --limit <limit>                 Show at most N of the matches returned by the api (optional)
 //! -n, --club-name <club-name>         Get your favorite Club ID by name (optional)
 //! -c, --club-id <club-id>             Your favorite Club ID (optional), if not specified `529 (Barcelona)` will be assumed
+//!     --standings                     Show the league table, requires `--league` and `--season`
+//!     --league <league>               League id (optional), without `--club-id` the matches of the whole league are shown
+//!     --season <season>               Season, the year the season starts in e.g. `2023` (optional)
+//!     --date <date>                   Matches on a single day as `YYYY-MM-DD` (optional)
+//!     --from <from>                   First day of a date range as `YYYY-MM-DD` (optional), requires `--to`
+//!     --to <to>                       Last day of a date range as `YYYY-MM-DD` (optional), requires `--from`
+//!     --events                        Append the goals, cards and substitutions timeline of the match
+//!     --lineups                       Append the starting XI, substitutes and coach of both sides
+//!     --stats                         Append the home vs away statistics (possession, shots, xG) of the match
+//!     --h2h <CLUB> <CLUB>             Head to head history between two clubs, each given by id or name
+//!     --timezone <timezone>           IANA name of the zone kick-off times are shown in e.g. `Europe/Madrid` (optional), the system local zone is used by default
+//! -o, --output <output>               Output format: `text` (default), `json`, `json-pretty`, `ics`, `csv` or `markdown`
+//!     --format <format>               Summarise the match for a status bar: `waybar`, `i3blocks` or `polybar`
+//!     --template <template>           Write out each match with a format string e.g. `{kickoff:%H:%M} {home} {home_goals}-{away_goals} {away}` (optional)
+//!     --watch                         Keep polling the matches and redraw them until the live ones are over (optional)
+//!     --interval <interval>           Seconds between two polls of a live match with `--watch` [default: 60]
+//!     --since-last-run                Only write out what changed in the matches since the previous run (optional)
+//!     --webhook <URL>                 POST each change as JSON to this url with `--watch` or `--since-last-run`, may be repeated (optional)
+//!     --no-cache                      Always ask the api, neither read nor write the response cache (optional)
+//!     --refresh                       Ask the api even if a cached response is still fresh, then cache the new one (optional)
+//!     --notify                        Send a desktop notification on kick-off, goals, red cards, half-time and full-time with `--watch` or `--since-last-run` (optional)
+//!
+//! SUBCOMMANDS:
+//! quota                               Show the plan of the api key and the requests used today and this minute
+//! serve                               Serve `/fixtures/live`, `/fixtures/live/stream`, `/fixtures/next`, `/teams/search?name=` and `/standings?league=&season=` as JSON
+//!     --bind <bind>                   Address to listen on [default: 127.0.0.1:8080]
+//!     --cache-ttl <cache-ttl>         Seconds a response is served from the cache [default: 30]
+//!     --interval <interval>           Seconds between two polls of a club streamed from `/fixtures/live/stream` [default: 60]
 
 /// Configuration data
 pub mod config;
@@ -30,12 +62,74 @@ pub mod config;
 /// Reqwest Client
 pub mod football_api;
 
+/// Retries of the failed api requests
+#[cfg(feature = "cli")]
+pub mod football_retry;
+
+/// On-disk cache of the api responses
+#[cfg(feature = "cli")]
+pub mod football_cache;
+
 /// Representation of Football Fixtures Data from api-football.com
 pub mod football_fixtures_data;
 
 /// Representation of Football Teams Data from api-football.com
 pub mod football_teams_data;
 
+/// Representation of Football Standings Data from api-football.com
+pub mod football_standings_data;
+
+/// Representation of Football Fixture Events Data from api-football.com
+pub mod football_events_data;
+
+/// Representation of Football Fixture Lineups Data from api-football.com
+pub mod football_lineups_data;
+
+/// Representation of Football Fixture Statistics Data from api-football.com
+pub mod football_statistics_data;
+
+/// Representation of the api-football.com account status
+pub mod football_status_data;
+
+/// Request quota reported by the api-football.com rate limit headers
+pub mod football_ratelimit;
+
+/// Machine readable output of the football data
+pub mod football_output;
+
+/// Status bar output of the football data
+pub mod football_statusbar;
+
+/// User defined output templates of the football data
+pub mod football_template;
+
+/// iCalendar export of the football fixtures
+pub mod football_ics;
+
+/// CSV and Markdown tables of the football data
+pub mod football_table;
+
+/// Polling schedule of the watch mode
+pub mod football_watch;
+
+/// Changes between two snapshots of the football fixtures
+pub mod football_changes;
+
+/// Last seen fixtures kept across runs
+pub mod football_state;
+
+/// Webhook deliveries of the fixture changes
+#[cfg(feature = "cli")]
+pub mod football_webhook;
+
+/// Desktop notifications of the fixture changes over D-Bus
+#[cfg(feature = "notify")]
+pub mod football_notify;
+
+/// Local HTTP server sharing cached football data between clients
+#[cfg(feature = "server")]
+pub mod football_server;
+
 /// CLI App Options and implementation
 pub mod football_opts;
 
@@ -26,6 +26,10 @@ async fn main() -> Result<(), Error> {
             }
             Ok(())
         }
+        Err(Error::QuotaError(e)) => {
+            eprintln!("Quota Error: {e}");
+            Ok(())
+        }
         Err(e) => Err(e),
     }
 }
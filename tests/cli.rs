@@ -1,6 +1,11 @@
 use anyhow::Error;
 use assert_cmd::{cargo::cargo_bin, Command};
 use log::info;
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    thread,
+};
 
 use footballscore::config::TestEnvs;
 
@@ -55,3 +60,157 @@ fn test_default() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Stand-in of the api answering every request with `body`
+fn spawn_upstream(body: &'static str) -> Result<String, Error> {
+    spawn_upstream_routes(&[("/", body)])
+}
+
+/// Stand-in of the api answering a request with the body of the first
+/// route its path starts with
+fn spawn_upstream_routes(routes: &[(&'static str, &'static str)]) -> Result<String, Error> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let api_endpoint = format!("http://{}/v3", listener.local_addr()?);
+    let routes = routes.to_vec();
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut buf = [0u8; 4096];
+            let Ok(len) = stream.read(&mut buf) else {
+                continue;
+            };
+            let request = String::from_utf8_lossy(&buf[..len]);
+            let path = request.split(' ').nth(1).unwrap_or_default();
+            let path = path.strip_prefix("/v3").unwrap_or(path);
+            let body = routes
+                .iter()
+                .find(|(route, _)| path.starts_with(route))
+                .map_or("{}", |(_, body)| body);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    Ok(api_endpoint)
+}
+
+#[test]
+fn test_local_upstream() -> Result<(), Error> {
+    let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID", "CACHE_DIR"]);
+    let cache_dir = tempfile::tempdir()?;
+    let api_endpoint = spawn_upstream(include_str!("resource/fixtures.json"))?;
+    let args = [
+        "-k",
+        "1e5765fc0c22df4e4ccf20581c2ef3d7",
+        "-c",
+        "529",
+        "--next-match",
+        "1",
+    ];
+
+    let output = Command::cargo_bin("footballscore")?
+        .env("API_ENDPOINT", &api_endpoint)
+        .env("CACHE_DIR", cache_dir.path())
+        .args(args)
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    info!("{}", stdout);
+    info!("{}", stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert!(stdout.contains("Barcelona"), "{stdout}");
+    assert!(std::fs::read_dir(cache_dir.path())?.count() > 0);
+
+    // another api is asked, not answered from the responses of the first
+    let api_endpoint = spawn_upstream("[]")?;
+    let output = Command::cargo_bin("footballscore")?
+        .env("API_ENDPOINT", &api_endpoint)
+        .env("CACHE_DIR", cache_dir.path())
+        .args(args)
+        .output()?;
+
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_lineups_json_passthrough() -> Result<(), Error> {
+    let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+    let lineups = include_str!("resource/lineups.json");
+    let api_endpoint = spawn_upstream_routes(&[
+        ("/fixtures/lineups", lineups),
+        ("/fixtures", include_str!("resource/fixtures.json")),
+    ])?;
+
+    let output = Command::cargo_bin("footballscore")?
+        .env("API_ENDPOINT", &api_endpoint)
+        .args([
+            "-k",
+            "1e5765fc0c22df4e4ccf20581c2ef3d7",
+            "-c",
+            "529",
+            "--lineups",
+            "--output",
+            "json",
+            "--no-cache",
+        ])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    // the lineups are written as the api sent them, not as modelled
+    let output: serde_json::Value = serde_json::from_str(&stdout)?;
+    let lineups: serde_json::Value = serde_json::from_str(lineups)?;
+    assert_eq!(output["fixtures"][0]["lineups"], lineups["response"]);
+
+    // a body that is not an object is an error, not a panic
+    let api_endpoint = spawn_upstream_routes(&[
+        ("/fixtures/lineups", "[]"),
+        ("/fixtures", include_str!("resource/fixtures.json")),
+    ])?;
+    let output = Command::cargo_bin("footballscore")?
+        .env("API_ENDPOINT", &api_endpoint)
+        .args(["-k", "1e5765fc0c22df4e4ccf20581c2ef3d7", "-c", "529"])
+        .args(["--lineups", "--output", "json", "--no-cache"])
+        .output()?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("panicked"), "{stderr}");
+    assert!(stderr.contains("have no `response`"), "{stderr}");
+
+    Ok(())
+}
+
+#[test]
+fn test_standings_json() -> Result<(), Error> {
+    let _env = TestEnvs::new(&["API_KEY", "API_ENDPOINT", "CLUB_ID"]);
+    let api_endpoint = spawn_upstream(include_str!("resource/standings.json"))?;
+
+    let output = Command::cargo_bin("footballscore")?
+        .env("API_ENDPOINT", &api_endpoint)
+        .args(["-k", "1e5765fc0c22df4e4ccf20581c2ef3d7", "--standings"])
+        .args(["--league", "140", "--season", "2023"])
+        .args(["--output", "json", "--no-cache"])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let output: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(output["leagues"][0]["name"], "La Liga");
+    assert_eq!(
+        output["leagues"][0]["tables"][0][1]["team"]["name"],
+        "Barcelona"
+    );
+
+    Ok(())
+}